@@ -0,0 +1,228 @@
+//! The parser of the [hashcat](https://hashcat.net/wiki/doku.php?id=mask_attack) mask syntax
+//!
+//! It is shared by the `hashcat` module of `bruteforce` and the `mask!` macro of
+//! `bruteforce-macros`, so both accept exactly the same masks.
+//! The syntax is documented in the `hashcat` module of `bruteforce`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate no_std_compat as std;
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::fmt;
+
+/// The maximum number of custom charsets
+pub const MAX_CUSTOM_CHARSETS: usize = 4;
+
+const LOWER: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPER: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const HEX_LOWER: &str = "0123456789abcdef";
+const HEX_UPPER: &str = "0123456789ABCDEF";
+const SPECIAL: &str = " !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// The reason why a mask could not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMaskErrorKind {
+    /// A `?` is followed by a char which is not a placeholder
+    UnknownPlaceholder(char),
+
+    /// A `?` is the last char
+    MissingPlaceholder,
+
+    /// A custom charset is used, but it was not defined
+    UndefinedCustomCharset(usize),
+
+    /// A custom charset is defined by an empty string
+    EmptyCustomCharset,
+
+    /// More than four custom charsets are defined
+    TooManyCustomCharsets,
+
+    /// The placeholder `?b` is used, whose bytes `0x80` to `0xff` are not chars
+    BytePlaceholder,
+}
+
+/// The error which is returned if a mask could not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMaskError {
+    /// The custom charset which contains the error, or `None` for the mask itself
+    pub custom_charset: Option<usize>,
+
+    /// The column of the offending char, counted in chars and starting at 1
+    pub column: usize,
+
+    /// The reason of the error
+    pub kind: ParseMaskErrorKind,
+}
+
+impl fmt::Display for ParseMaskError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.kind {
+            ParseMaskErrorKind::UnknownPlaceholder(c) => {
+                write!(fmt, "unknown placeholder `?{}`", c)
+            }
+            ParseMaskErrorKind::MissingPlaceholder => write!(fmt, "missing placeholder after `?`"),
+            ParseMaskErrorKind::UndefinedCustomCharset(n) => {
+                write!(fmt, "custom charset `?{}` is not defined", n)
+            }
+            ParseMaskErrorKind::EmptyCustomCharset => write!(fmt, "empty custom charset"),
+            ParseMaskErrorKind::TooManyCustomCharsets => {
+                write!(fmt, "there are at most four custom charsets")
+            }
+            ParseMaskErrorKind::BytePlaceholder => {
+                write!(
+                    fmt,
+                    "the byte placeholder `?b` is not supported for strings"
+                )
+            }
+        }?;
+        match self.custom_charset {
+            Some(n) => write!(fmt, " at column {} of custom charset {}", self.column, n),
+            None => write!(fmt, " at column {} of mask", self.column),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMaskError {}
+
+/// Returns the chars of a built-in placeholder
+fn builtin(placeholder: char) -> Option<String> {
+    let chars = match placeholder {
+        'l' => LOWER.to_string(),
+        'u' => UPPER.to_string(),
+        'd' => DIGITS.to_string(),
+        'h' => HEX_LOWER.to_string(),
+        'H' => HEX_UPPER.to_string(),
+        's' => SPECIAL.to_string(),
+        'a' => [LOWER, UPPER, DIGITS, SPECIAL].concat(),
+        _ => return None,
+    };
+    Some(chars)
+}
+
+/// Splits a mask or a custom charset definition into the chars of every position
+///
+/// `custom` contains the already parsed custom charsets, or `None`
+/// if custom charsets may not be used.
+fn tokenize(
+    s: &str,
+    custom: Option<&[String]>,
+    custom_charset: Option<usize>,
+) -> Result<Vec<String>, ParseMaskError> {
+    let error = |column, kind| ParseMaskError {
+        custom_charset,
+        column,
+        kind,
+    };
+
+    let mut positions = Vec::new();
+    let mut chars = s.chars().enumerate();
+    while let Some((i, c)) = chars.next() {
+        let column = i + 1;
+        if c != '?' {
+            positions.push(c.to_string());
+            continue;
+        }
+
+        let placeholder = match chars.next() {
+            Some((_, placeholder)) => placeholder,
+            None => return Err(error(column, ParseMaskErrorKind::MissingPlaceholder)),
+        };
+        if placeholder == '?' {
+            positions.push('?'.to_string());
+        } else if placeholder == 'b' {
+            // Hashcat tries single bytes, but the bytes from 0x80 on are two bytes in UTF-8
+            return Err(error(column, ParseMaskErrorKind::BytePlaceholder));
+        } else if let Some(chars) = builtin(placeholder) {
+            positions.push(chars);
+        } else {
+            let n = placeholder
+                .to_digit(10)
+                .map(|n| n as usize)
+                .filter(|n| (1..=MAX_CUSTOM_CHARSETS).contains(n));
+            match (n, custom) {
+                (Some(n), Some(custom)) => match custom.get(n - 1) {
+                    Some(chars) => positions.push(chars.clone()),
+                    None => {
+                        return Err(error(column, ParseMaskErrorKind::UndefinedCustomCharset(n)))
+                    }
+                },
+                _ => {
+                    return Err(error(
+                        column,
+                        ParseMaskErrorKind::UnknownPlaceholder(placeholder),
+                    ))
+                }
+            }
+        }
+    }
+    Ok(positions)
+}
+
+/// Parses a custom charset definition like `?l?d_`
+///
+/// The chars are deduplicated and keep their order.
+fn parse_custom_charset(s: &str, n: usize) -> Result<String, ParseMaskError> {
+    if s.is_empty() {
+        return Err(ParseMaskError {
+            custom_charset: Some(n),
+            column: 1,
+            kind: ParseMaskErrorKind::EmptyCustomCharset,
+        });
+    }
+
+    let mut chars = String::new();
+    for c in tokenize(s, None, Some(n))?.iter().flat_map(|s| s.chars()) {
+        if !chars.contains(c) {
+            chars.push(c);
+        }
+    }
+    Ok(chars)
+}
+
+/// Parses a hashcat mask with up to four custom charsets
+///
+/// Returns the chars of every position of the mask.
+///
+/// # Arguments
+///
+/// * `mask` - The hashcat mask, e.g. `?u?l?l?d`
+/// * `custom_charsets` - The definitions of the custom charsets `-1` to `-4`, e.g. `?l?d`
+///
+/// # Errors
+///
+/// Returns a [`ParseMaskError`] with the custom charset and the column of the offending char.
+///
+/// [`ParseMaskError`]: struct.ParseMaskError.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce_hashcat::{parse, ParseMaskErrorKind};
+///
+/// assert_eq!(parse("?1-?d", &["xy"]).unwrap()[..2], ["xy", "-"]);
+/// assert_eq!(parse("?u?x", &[]).unwrap_err().column, 3);
+/// assert_eq!(
+///     parse("?1", &["a", "b", "c", "d", "e"]).unwrap_err().kind,
+///     ParseMaskErrorKind::TooManyCustomCharsets
+/// );
+/// ```
+pub fn parse(mask: &str, custom_charsets: &[&str]) -> Result<Vec<String>, ParseMaskError> {
+    if custom_charsets.len() > MAX_CUSTOM_CHARSETS {
+        return Err(ParseMaskError {
+            custom_charset: Some(MAX_CUSTOM_CHARSETS + 1),
+            column: 1,
+            kind: ParseMaskErrorKind::TooManyCustomCharsets,
+        });
+    }
+    let custom = custom_charsets
+        .iter()
+        .enumerate()
+        .map(|(i, s)| parse_custom_charset(s, i + 1))
+        .collect::<Result<Vec<String>, ParseMaskError>>()?;
+    tokenize(mask, Some(&custom), None)
+}
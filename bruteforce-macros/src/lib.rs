@@ -5,21 +5,147 @@ extern crate proc_macro;
 #[cfg(not(feature = "std"))]
 extern crate no_std_compat as std;
 
+mod mask;
+
 use proc_macro::TokenStream;
 
-use std::collections::HashSet;
 use std::prelude::v1::*; // needed for std-compat
 
 use quote::quote;
+use syn::{parse_macro_input, Error, LitStr};
+
+/// Parses the chars of a charset literal, in which `a-z` stands for all chars from `a` to `z`
+///
+/// A `-` at the start or the end of the literal is a char itself.
+fn parse_charset(literal: &LitStr) -> Result<Vec<char>, Error> {
+    let source = literal.value().chars().collect::<Vec<char>>();
+    if source.is_empty() {
+        return Err(Error::new(
+            literal.span(),
+            "The charset must contain at least one character",
+        ));
+    }
+
+    let mut chars = Vec::new();
+    let mut i = 0;
+    while i < source.len() {
+        let (start, end, width) = match source.get(i + 1..i + 3) {
+            Some(&['-', end]) => (source[i], end, 3),
+            _ => (source[i], source[i], 1),
+        };
+        if start > end {
+            return Err(Error::new(
+                literal.span(),
+                format!("The range `{}-{}` must not be reversed", start, end),
+            ));
+        }
+        for c in start..=end {
+            if chars.contains(&c) {
+                return Err(Error::new(
+                    literal.span(),
+                    format!("The character `{}` must not be contained more than once", c),
+                ));
+            }
+            chars.push(c);
+        }
+        i += width;
+    }
+    Ok(chars)
+}
+
+/// Generates a `Charset` from a string literal at compile time
+///
+/// The chars keep the order of the literal, so the indices of a brute forcer
+/// are the same on every compile. `a-z` stands for all chars from `a` to `z`
+/// and a `-` at the start or the end of the literal is a char itself.
+///
+/// The expansion is a call of the `const fn` `Charset::new`, so it can be used in `const` items.
+/// An empty literal, a reversed range or a repeated char is a compile error.
+///
+/// ```rust
+/// use bruteforce::charset;
+/// use bruteforce::charset::Charset;
+///
+/// const HEX: Charset = charset!("0-9a-f");
+/// assert_eq!(HEX.to_string(), "0123456789abcdef");
+///
+/// assert_eq!(charset!("a-c_-").to_string(), "abc_-");
+/// ```
+///
+/// ```compile_fail
+/// use bruteforce::charset;
+///
+/// let charset = charset!("a-za");
+/// ```
+#[proc_macro]
+pub fn charset(item: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(item as LitStr);
+    match parse_charset(&literal) {
+        Ok(chars) => TokenStream::from(quote! {
+            ::bruteforce::charset::Charset::new(&[#(#chars),*])
+        }),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
 
-/// Converts an string into a slice
-#[doc(hidden)]
+/// Generates the type of a fixed-length generator from a hashcat mask at compile time
+///
+/// The input is the name of the type, optionally preceded by attributes and a visibility,
+/// followed by `=` and the mask. The mask is parsed by the same parser as in the `hashcat` module
+/// of `bruteforce`.
+/// It can be followed by up to four custom charsets for `?1` to `?4`. The charset of every
+/// position and the increment are generated at compile time, so it is faster than `MaskBruteForce`.
+///
+/// The type is an `Iterator` over `String`, which is created by `new` or `Default`. Like
+/// `MaskBruteForce`, it also has the methods `try_raw_next`, `raw_next` and `remaining`,
+/// and the last position changes fastest. An invalid mask is a compile error,
+/// which names the column of the offending char.
+///
+/// ```rust
+/// use bruteforce::mask;
+///
+/// mask!(
+///     /// Every string of an uppercase letter, a lowercase letter and two digits
+///     pub Word = "?u?l?d?d"
+/// );
+///
+/// let mut generator = Word::new();
+/// assert_eq!(generator.remaining(), 26 * 26 * 10 * 10);
+/// assert_eq!(generator.raw_next(), "Aa00");
+/// assert_eq!(generator.raw_next(), "Aa01");
+/// assert_eq!(generator.last(), Some("Zz99".to_string()));
+///
+/// mask!(Custom = "?1-?d", "xyz");
+///
+/// let generator = Custom::new();
+/// assert_eq!(generator.collect::<Vec<String>>()[9..12], ["x-9", "y-0", "y-1"]);
+/// ```
+///
+/// The type can be named like any other type, e.g. in a struct:
+///
+/// ```rust
+/// use bruteforce::mask;
+///
+/// mask!(Pin4 = "?d?d?d?d");
+///
+/// struct Lock {
+///     pins: Pin4,
+/// }
+///
+/// let mut lock = Lock { pins: Pin4::new() };
+/// assert_eq!(lock.pins.nth(1234), Some("1234".to_string()));
+/// ```
+///
+/// ```compile_fail
+/// use bruteforce::mask;
+///
+/// mask!(Invalid = "?u?x");
+/// ```
 #[proc_macro]
-pub fn charset_string(item: TokenStream) -> TokenStream {
-    assert!(!item.is_empty(), "Cannot parse empty string as charset");
-    let chars: HashSet<char> = item.to_string().chars().collect(); // hashset, because duplicates are not permitted
-    let iter = chars.into_iter();
-    TokenStream::from(quote! {
-        let slice = &[#(#iter),*];
-    })
+pub fn mask(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as mask::MaskInput);
+    match mask::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
 }
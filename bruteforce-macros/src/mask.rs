@@ -0,0 +1,181 @@
+use std::prelude::v1::*; // needed for std-compat
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, Ident, LitStr, Token, Visibility};
+
+/// The arguments of `mask!`: the generated type, the mask and up to four custom charsets
+///
+/// The syntax is `mask!(#[attributes] visibility Name = "mask", "custom charset", ...)`.
+pub struct MaskInput {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    mask: LitStr,
+    custom_charsets: Vec<LitStr>,
+}
+
+impl Parse for MaskInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        let mut literals = Punctuated::<LitStr, Token![,]>::parse_terminated(input)?.into_iter();
+        let mask = literals
+            .next()
+            .ok_or_else(|| input.error("expected a hashcat mask"))?;
+        let custom_charsets = literals.collect::<Vec<LitStr>>();
+        Ok(MaskInput {
+            attrs,
+            vis,
+            name,
+            mask,
+            custom_charsets,
+        })
+    }
+}
+
+/// Generates the type of the fixed-length generator of a mask
+pub fn expand(input: MaskInput) -> Result<TokenStream, Error> {
+    let custom = input
+        .custom_charsets
+        .iter()
+        .map(LitStr::value)
+        .collect::<Vec<String>>();
+    let custom = custom.iter().map(String::as_str).collect::<Vec<&str>>();
+    // The error message contains the column, the span points to the literal containing it
+    let positions = bruteforce_hashcat::parse(&input.mask.value(), &custom).map_err(|error| {
+        let literal = match error.custom_charset {
+            Some(n) => &input.custom_charsets[n - 1],
+            None => &input.mask,
+        };
+        Error::new(literal.span(), error)
+    })?;
+    if positions.is_empty() {
+        return Err(Error::new(
+            input.mask.span(),
+            "The mask must contain at least one position",
+        ));
+    }
+    let total = positions
+        .iter()
+        .try_fold(1u128, |total, chars| {
+            total.checked_mul(chars.chars().count() as u128)
+        })
+        .ok_or_else(|| Error::new(input.mask.span(), "The keyspace must fit in a u128"))?;
+
+    let (attrs, vis, name) = (&input.attrs, &input.vis, &input.name);
+    let len = positions.len();
+    // Every char is stored as &str, so a position is appended without encoding it
+    let tables = positions.iter().map(|chars| {
+        let chars = chars.chars().map(|c| c.to_string());
+        quote! { &[#(#chars),*] }
+    });
+
+    // The increment is unrolled from the last position to the first one.
+    // `changed` becomes the first position which must be rewritten.
+    let increment = (0..len).fold(quote! { self.changed = 0; }, |carry, position| {
+        quote! {
+            if self.indices[#position] + 1 < Self::CHARSETS[#position].len() {
+                self.indices[#position] += 1;
+                self.changed = #position;
+            } else {
+                self.indices[#position] = 0;
+                #carry
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Debug, Clone)]
+        #vis struct #name {
+            /// This is the current string
+            current: ::bruteforce::__private::String,
+
+            /// The index of the char of every position
+            indices: [usize; #len],
+
+            /// The byte offset of every position in `current`
+            offsets: [usize; #len],
+
+            /// The first position which must be rewritten
+            changed: usize,
+
+            /// The number of strings left
+            remaining: u128,
+        }
+
+        #[allow(dead_code)]
+        impl #name {
+            /// The chars of every position
+            const CHARSETS: [&'static [&'static str]; #len] = [#(#tables),*];
+
+            /// Returns a generator which starts with the first string of the mask
+            #vis fn new() -> #name {
+                #name {
+                    current: ::bruteforce::__private::String::new(),
+                    indices: [0; #len],
+                    offsets: [0; #len],
+                    changed: 0,
+                    remaining: #total,
+                }
+            }
+
+            /// Returns the number of strings left
+            #vis fn remaining(&self) -> u128 {
+                self.remaining
+            }
+
+            /// This returns the next element, or `None` if the keyspace is exhausted
+            #vis fn try_raw_next(&mut self) -> ::core::option::Option<&str> {
+                if self.remaining == 0 {
+                    return ::core::option::Option::None;
+                }
+                self.remaining -= 1;
+
+                self.current.truncate(self.offsets[self.changed]);
+                for position in self.changed..#len {
+                    self.offsets[position] = self.current.len();
+                    self.current.push_str(Self::CHARSETS[position][self.indices[position]]);
+                }
+                #increment
+
+                ::core::option::Option::Some(&self.current)
+            }
+
+            /// This returns the next element without unnecessary boxing in a Option
+            #vis fn raw_next(&mut self) -> &str {
+                self.try_raw_next()
+                    .expect("The keyspace of the brute forcer is exhausted")
+            }
+        }
+
+        impl ::core::default::Default for #name {
+            fn default() -> #name {
+                #name::new()
+            }
+        }
+
+        impl ::core::iter::Iterator for #name {
+            type Item = ::bruteforce::__private::String;
+
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                self.try_raw_next().map(::bruteforce::__private::ToString::to_string)
+            }
+
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                match <usize as ::core::convert::TryFrom<u128>>::try_from(self.remaining) {
+                    ::core::result::Result::Ok(remaining) => {
+                        (remaining, ::core::option::Option::Some(remaining))
+                    }
+                    ::core::result::Result::Err(_) => (usize::MAX, ::core::option::Option::None),
+                }
+            }
+        }
+    })
+}
@@ -1,5 +1,7 @@
+use bruteforce::bytes::ByteBruteForce;
 use bruteforce::charset::Charset;
-use bruteforce::BruteForce;
+use bruteforce::mask::MaskBruteForce;
+use bruteforce::{mask, BruteForce};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 const BENCH_CHARS: Charset = Charset::new(&[
@@ -19,6 +21,44 @@ fn bench_raw_next(c: &mut Criterion) {
     });
 }
 
+fn bench_raw_next_long(c: &mut Criterion) {
+    c.bench_function("bench_raw_next_long", |b| {
+        let mut brute_forcer = BruteForce::new_at(black_box(BENCH_CHARS), 32);
+        b.iter(|| {
+            brute_forcer.raw_next();
+        });
+    });
+}
+
+fn bench_bytes_raw_next(c: &mut Criterion) {
+    c.bench_function("bench_bytes_raw_next", |b| {
+        let alphabet = (0..=255).collect::<Vec<u8>>();
+        let mut brute_forcer = ByteBruteForce::new(black_box(alphabet));
+        b.iter(|| {
+            brute_forcer.raw_next();
+        });
+    });
+}
+
+fn bench_mask_raw_next(c: &mut Criterion) {
+    c.bench_function("bench_mask_raw_next", |b| {
+        let mut brute_forcer: MaskBruteForce = black_box("?a?a?a?a?a?a?a?a").parse().unwrap();
+        b.iter(|| {
+            brute_forcer.raw_next();
+        });
+    });
+}
+
+fn bench_mask_macro_raw_next(c: &mut Criterion) {
+    c.bench_function("bench_mask_macro_raw_next", |b| {
+        mask!(Generator = "?a?a?a?a?a?a?a?a");
+        let mut generator = Generator::new();
+        b.iter(|| {
+            generator.raw_next();
+        });
+    });
+}
+
 fn bench_next(c: &mut Criterion) {
     c.bench_function("bench_next", |b| {
         let mut brute_forcer = BruteForce::new(black_box(BENCH_CHARS));
@@ -81,6 +121,10 @@ fn bench_charset_to_string(c: &mut Criterion) {
 criterion_group!(
     all,
     bench_raw_next,
+    bench_raw_next_long,
+    bench_bytes_raw_next,
+    bench_mask_raw_next,
+    bench_mask_macro_raw_next,
     bench_next,
     bench_new,
     bench_charset_new,
@@ -0,0 +1,300 @@
+use std::prelude::v1::*; // needed for std-compat
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::ops::{Deref, RangeInclusive};
+
+use crate::error::Error;
+use crate::{increment, offset_of_length, Bounds};
+
+/// Represents a brute-forcing instance over an alphabet of bytes
+///
+/// Unlike [`BruteForce`], the strings are byte strings, so they do not need to be valid UTF-8.
+/// This is useful to brute force binary keys, e.g. XOR keys or nonces.
+/// The strings are generated in the same order as by [`BruteForce`].
+///
+/// [`BruteForce`]: ../struct.BruteForce.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::bytes::ByteBruteForce;
+///
+/// let secret = [0xff, 0x42];
+/// let brute_forcer = ByteBruteForce::new_bounded((0..=255).collect::<Vec<u8>>(), 2..=2);
+///
+/// assert_eq!(brute_forcer.len(), 256 * 256);
+/// assert!(brute_forcer.into_iter().any(|s| s == secret));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteBruteForce<'a> {
+    /// Represents the alphabet of the brute-forcer
+    alphabet: Cow<'a, [u8]>,
+
+    /// This is the current byte string, of which only the changed suffix is rewritten
+    ///
+    /// It is private, so it is only handed out by `raw_next` and `try_raw_next`.
+    current: Vec<u8>,
+
+    /// Reversed representation of current where each element is an index of the alphabet
+    raw_current: Vec<usize>,
+
+    /// The number of positions of `raw_current` which changed since `current` was written
+    changed: usize,
+
+    /// The remaining part of the keyspace, if the brute forcer is bounded
+    bounds: Option<Bounds>,
+}
+
+impl<'a> ByteBruteForce<'a> {
+    /// Returns a brute forcer with default settings
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - A byte slice or vector that contains all bytes to be tried
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::bytes::ByteBruteForce;
+    /// let mut brute_forcer = ByteBruteForce::new(&[0x00, 0x01][..]);
+    ///
+    /// assert_eq!(brute_forcer.raw_next(), b"");
+    /// assert_eq!(brute_forcer.raw_next(), b"\x00");
+    /// assert_eq!(brute_forcer.raw_next(), b"\x01");
+    /// assert_eq!(brute_forcer.raw_next(), b"\x00\x00");
+    /// ```
+    pub fn new(alphabet: impl Into<Cow<'a, [u8]>>) -> ByteBruteForce<'a> {
+        ByteBruteForce::new_at(alphabet, 0)
+    }
+
+    /// Returns a brute forcer skipping some byte strings
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - A byte slice or vector that contains all bytes to be tried
+    /// * `start` - E.g. the known key length
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet is empty.
+    pub fn new_at(alphabet: impl Into<Cow<'a, [u8]>>, start: usize) -> ByteBruteForce<'a> {
+        Self::try_new_at(alphabet, start).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer skipping some byte strings, or an error if the alphabet is empty
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyCharset`] if the alphabet is empty.
+    ///
+    /// [`Error::EmptyCharset`]: ../error/enum.Error.html#variant.EmptyCharset
+    pub fn try_new_at(
+        alphabet: impl Into<Cow<'a, [u8]>>,
+        start: usize,
+    ) -> Result<ByteBruteForce<'a>, Error> {
+        let alphabet = alphabet.into();
+        if alphabet.is_empty() {
+            return Err(Error::EmptyCharset);
+        }
+        Ok(ByteBruteForce {
+            alphabet,
+            current: Vec::default(),
+            raw_current: vec![0; start],
+            changed: 0,
+            bounds: None,
+        })
+    }
+
+    /// Returns a brute forcer which only tries byte strings within a length range
+    ///
+    /// Unlike the other constructors, the brute forcer stops after the last byte string
+    /// of the maximum length, so it is a [`BoundedByteBruteForce`] with an exact length.
+    ///
+    /// [`BoundedByteBruteForce`]: struct.BoundedByteBruteForce.html
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - A byte slice or vector that contains all bytes to be tried
+    /// * `lengths` - The minimum and maximum length of the byte strings
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet or the range is empty, or if the keyspace does not fit in a `u128`.
+    pub fn new_bounded(
+        alphabet: impl Into<Cow<'a, [u8]>>,
+        lengths: RangeInclusive<usize>,
+    ) -> BoundedByteBruteForce<'a> {
+        Self::try_new_bounded(alphabet, lengths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which only tries byte strings within a length range, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyCharset`] if the alphabet is empty, [`Error::EmptyLengthRange`]
+    /// if the range is empty and [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::EmptyCharset`]: ../error/enum.Error.html#variant.EmptyCharset
+    /// [`Error::EmptyLengthRange`]: ../error/enum.Error.html#variant.EmptyLengthRange
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    pub fn try_new_bounded(
+        alphabet: impl Into<Cow<'a, [u8]>>,
+        lengths: RangeInclusive<usize>,
+    ) -> Result<BoundedByteBruteForce<'a>, Error> {
+        let (min, max) = (*lengths.start(), *lengths.end());
+        if min > max {
+            return Err(Error::EmptyLengthRange);
+        }
+        let mut brute_forcer = ByteBruteForce::try_new_at(alphabet, min)?;
+        let next = offset_of_length(brute_forcer.alphabet.len(), min);
+        let end = max
+            .checked_add(1)
+            .and_then(|len| offset_of_length(brute_forcer.alphabet.len(), len));
+        let (next, end) = next.zip(end).ok_or(Error::KeyspaceTooLarge)?;
+        brute_forcer.bounds = Some(Bounds { next, end });
+        Ok(BoundedByteBruteForce { brute_forcer })
+    }
+
+    /// Returns the alphabet of the brute forcer
+    pub fn alphabet(&self) -> &[u8] {
+        &self.alphabet
+    }
+
+    /// Returns the number of byte strings left, or `None` if the brute forcer is unbounded
+    pub fn remaining(&self) -> Option<u128> {
+        self.bounds.map(|bounds| bounds.end - bounds.next)
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace of a bounded brute forcer is exhausted.
+    pub fn raw_next(&mut self) -> &[u8] {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+
+    /// This returns the next element, or `None` if the keyspace of a bounded brute forcer is exhausted
+    ///
+    /// The returned slice is a reused buffer, so only the changed bytes are written.
+    pub fn try_raw_next(&mut self) -> Option<&[u8]> {
+        if let Some(bounds) = &mut self.bounds {
+            if bounds.next == bounds.end {
+                return None;
+            }
+            bounds.next += 1;
+        }
+
+        let cur = &mut self.current;
+        let alphabet = &self.alphabet;
+        let len = self.raw_current.len();
+
+        if cur.len() == len {
+            // Only the last positions changed since the last call
+            for (position, &i) in self.raw_current.iter().enumerate().take(self.changed) {
+                cur[len - 1 - position] = alphabet[i];
+            }
+        } else {
+            cur.clear();
+            cur.extend(self.raw_current.iter().rev().map(|&i| alphabet[i]));
+        }
+
+        // "Add" 1 to self.raw_current
+        let alphabet_len = alphabet.len();
+        match increment(&mut self.raw_current, |_| alphabet_len) {
+            Some(changed) => self.changed = changed,
+            None => self.raw_current.push(0),
+        }
+
+        Some(&self.current)
+    }
+}
+
+impl<'a> Iterator for ByteBruteForce<'a> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        self.try_raw_next().map(<[u8]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining().map(usize::try_from) {
+            Some(Ok(remaining)) => (remaining, Some(remaining)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// Represents a byte brute-forcing instance with a finite keyspace
+///
+/// It is returned by [`ByteBruteForce::new_bounded`] and dereferences to the brute forcer.
+/// Unlike an unbounded brute forcer, it has an exact length.
+///
+/// [`ByteBruteForce::new_bounded`]: struct.ByteBruteForce.html#method.new_bounded
+#[derive(Debug, Clone)]
+pub struct BoundedByteBruteForce<'a> {
+    /// The brute forcer, whose bounds are always set
+    brute_forcer: ByteBruteForce<'a>,
+}
+
+impl<'a> BoundedByteBruteForce<'a> {
+    /// Returns the brute forcer without the guarantee that it is bounded
+    pub fn into_inner(self) -> ByteBruteForce<'a> {
+        self.brute_forcer
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &[u8] {
+        self.brute_forcer.raw_next()
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    pub fn try_raw_next(&mut self) -> Option<&[u8]> {
+        self.brute_forcer.try_raw_next()
+    }
+}
+
+/// Only reading methods are available, so the brute forcer stays bounded
+impl<'a> Deref for BoundedByteBruteForce<'a> {
+    type Target = ByteBruteForce<'a>;
+
+    fn deref(&self) -> &ByteBruteForce<'a> {
+        &self.brute_forcer
+    }
+}
+
+impl Iterator for BoundedByteBruteForce<'_> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Vec<u8>> {
+        self.brute_forcer.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.brute_forcer.size_hint()
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`ByteBruteForce::remaining`] in this case.
+///
+/// [`ByteBruteForce::remaining`]: struct.ByteBruteForce.html#method.remaining
+impl ExactSizeIterator for BoundedByteBruteForce<'_> {
+    fn len(&self) -> usize {
+        self.remaining()
+            .and_then(|remaining| usize::try_from(remaining).ok())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
@@ -5,15 +5,7 @@ use std::ops::Index;
 use std::ops::RangeInclusive;
 use std::slice::Iter;
 
-#[cfg(feature = "bruteforce-macros")]
-#[macro_export]
-/// The charset macro generates a `Charset` by a string literal
-macro_rules! charset {
-    ($string:expr) => {{
-        charset_string!($string);
-        Charset::new(slice)
-    }};
-}
+use crate::error::Error;
 
 /// The charset representation for bruteforce
 ///
@@ -43,7 +35,7 @@ impl<'a> Charset<'a> {
     ///
     /// Charset::new(&['A', 'B', 'C', 'D', 'E']);
     /// ```
-    pub const fn new(charset: &[char]) -> Charset {
+    pub const fn new(charset: &[char]) -> Charset<'_> {
         assert!(
             !charset.is_empty(),
             "The &[char] must contain at least one character"
@@ -53,12 +45,40 @@ impl<'a> Charset<'a> {
         }
     }
 
+    /// This creates a new charset by a defined slice of chars, or returns an error if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char slice which contains the chars
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::error::Error;
+    ///
+    /// assert!(Charset::try_new(&['A', 'B', 'C']).is_ok());
+    /// assert_eq!(Charset::try_new(&[]).unwrap_err(), Error::EmptyCharset);
+    /// ```
+    pub const fn try_new(charset: &[char]) -> Result<Charset<'_>, Error> {
+        if charset.is_empty() {
+            return Err(Error::EmptyCharset);
+        }
+        Ok(Charset {
+            chars: Cow::Borrowed(charset),
+        })
+    }
+
     /// This function creates a new charset by a defined char range
     ///
     /// # Arguments
     ///
     /// * `range` - A char range
     ///
+    /// # Panics
+    ///
+    /// Panics if the range does not contain any valid char.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -67,11 +87,35 @@ impl<'a> Charset<'a> {
     /// Charset::by_char_range('a'..='z'); // = abcdefghijklmnopqrstuvwxyz
     /// ```
     pub fn by_char_range(range: RangeInclusive<char>) -> Charset<'a> {
+        Self::try_by_char_range(range).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// This function creates a new charset by a defined char range, or returns an error if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - A char range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::error::Error;
+    ///
+    /// assert_eq!(Charset::try_by_char_range('a'..='c').unwrap().len(), 3);
+    /// assert_eq!(Charset::try_by_char_range('z'..='a').unwrap_err(), Error::EmptyCharset);
+    /// ```
+    pub fn try_by_char_range(range: RangeInclusive<char>) -> Result<Charset<'a>, Error> {
         let range_as_u32 = (*range.start() as u32)..=(*range.end() as u32);
-        let vec = range_as_u32.filter_map(std::char::from_u32).collect();
-        Charset {
-            chars: Cow::Owned(vec),
+        let vec = range_as_u32
+            .filter_map(std::char::from_u32)
+            .collect::<Vec<char>>();
+        if vec.is_empty() {
+            return Err(Error::EmptyCharset);
         }
+        Ok(Charset {
+            chars: Cow::Owned(vec),
+        })
     }
 
     /// This function creates a new charset by a defined char range ignoring utf-validity
@@ -88,6 +132,10 @@ impl<'a> Charset<'a> {
     ///
     /// [`by_char_range`]: fn.by_char_range.html
     ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -96,6 +144,9 @@ impl<'a> Charset<'a> {
     /// let charset = unsafe { Charset::by_char_range_unchecked('a'..='z') };
     /// ```
     pub unsafe fn by_char_range_unchecked(range: RangeInclusive<char>) -> Charset<'a> {
+        if range.is_empty() {
+            panic!("{}", Error::EmptyCharset);
+        }
         let range_as_u32 = (*range.start() as u32)..=(*range.end() as u32);
         let vec = range_as_u32
             .map(|c| std::char::from_u32_unchecked(c))
@@ -122,14 +173,32 @@ impl<'a> Charset<'a> {
     /// Charset::from("ABCDEFGHIJKLMNOPRSTUVWXYZ");
     /// ```
     pub fn new_by_str(s: &str) -> Charset<'a> {
-        assert!(
-            !s.is_empty(),
-            "The string must contain at least one character"
-        );
+        Self::try_new_by_str(s).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// This function creates a charset by &str, or returns an error if it is empty
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The char slice from `new` but easier to write
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::error::Error;
+    ///
+    /// assert!(Charset::try_new_by_str("ABC").is_ok());
+    /// assert_eq!(Charset::try_new_by_str("").unwrap_err(), Error::EmptyCharset);
+    /// ```
+    pub fn try_new_by_str(s: &str) -> Result<Charset<'a>, Error> {
+        if s.is_empty() {
+            return Err(Error::EmptyCharset);
+        }
         let vec = s.chars().collect::<Vec<char>>();
-        Charset {
+        Ok(Charset {
             chars: Cow::Owned(vec),
-        }
+        })
     }
 
     /// This function concat's 2 charsets
@@ -182,6 +251,12 @@ impl<'a> Charset<'a> {
         self.chars.is_empty()
     }
 
+    /// This function returns the internal char slice
+    #[inline]
+    pub fn as_slice(&self) -> &[char] {
+        &self.chars
+    }
+
     /// This function returns the immutable iterator of the internal char slice
     #[inline]
     pub fn iter(&self) -> Iter<'_, char> {
@@ -198,12 +273,22 @@ impl Index<usize> for Charset<'_> {
     }
 }
 
+/// # Panics
+///
+/// `from` panics if the string is empty. Use [`Charset::try_new_by_str`] for user input.
+///
+/// [`Charset::try_new_by_str`]: struct.Charset.html#method.try_new_by_str
 impl<'a> From<&'a str> for Charset<'_> {
     fn from(input: &'a str) -> Self {
         Self::new_by_str(input)
     }
 }
 
+/// # Panics
+///
+/// `from` panics if the string is empty. Use [`Charset::try_new_by_str`] for user input.
+///
+/// [`Charset::try_new_by_str`]: struct.Charset.html#method.try_new_by_str
 impl From<String> for Charset<'_> {
     fn from(s: String) -> Self {
         s.as_str().into()
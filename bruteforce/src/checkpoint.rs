@@ -0,0 +1,212 @@
+//! Serialization of charsets and brute forcers, which is used to save and resume progress
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::convert::TryFrom;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::charset::Charset;
+use crate::order::Order;
+use crate::{offset_of_length, BoundedBruteForce, Bounds, BruteForce};
+
+/// A charset is serialized as a string of its chars
+impl Serialize for Charset<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Charset<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Charset::try_new_by_str(&s).map_err(D::Error::custom)
+    }
+}
+
+/// The serialized form of a brute forcer
+///
+/// The position is stored as `raw_current`, because the global index
+/// of an unbounded brute forcer may not fit in a `u128`.
+/// Bounded brute forcers store their index as `next` as well,
+/// because `raw_current` is meaningless once they are exhausted.
+#[derive(Serialize)]
+#[serde(rename = "BruteForce")]
+struct BruteForceRef<'s, 'a> {
+    chars: &'s Charset<'a>,
+    raw_current: &'s [usize],
+    next: Option<u128>,
+    end: Option<u128>,
+    order: Order,
+    lengths: Option<(usize, usize)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "BruteForce")]
+struct BruteForceState<'a> {
+    chars: Charset<'a>,
+    raw_current: Vec<usize>,
+    #[serde(default)]
+    next: Option<u128>,
+    end: Option<u128>,
+    #[serde(default)]
+    order: Order,
+    #[serde(default)]
+    lengths: Option<(usize, usize)>,
+}
+
+/// A brute forcer is serialized with its charset, position and bounds
+///
+/// This way a long-running brute forcer can write a checkpoint and resume exactly where it left off.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// use bruteforce::BoundedBruteForce;
+/// let mut brute_forcer = BruteForce::new_bounded(Charset::from("ABC"), 1..=4);
+/// brute_forcer.nth(41);
+///
+/// let checkpoint = serde_json::to_string(&brute_forcer).unwrap();
+/// let mut resumed: BoundedBruteForce = serde_json::from_str(&checkpoint).unwrap();
+///
+/// assert_eq!(resumed.remaining(), brute_forcer.remaining());
+/// assert_eq!(resumed.next(), brute_forcer.next());
+/// ```
+///
+/// The order is saved as well:
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// use bruteforce::order::Order;
+/// let brute_forcer = BruteForce::new_bounded(Charset::from("ABC"), 1..=4);
+/// let mut brute_forcer = brute_forcer.with_order(Order::DepthFirst).unwrap();
+/// brute_forcer.nth(41);
+///
+/// let checkpoint = serde_json::to_string(&brute_forcer).unwrap();
+/// let resumed: BruteForce = serde_json::from_str(&checkpoint).unwrap();
+///
+/// assert_eq!(resumed.order(), Order::DepthFirst);
+/// assert!(resumed.eq(brute_forcer));
+/// ```
+///
+/// A malformed checkpoint is an error:
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// let checkpoint = r#"{"chars": "A", "raw_current": [], "next": 1000000000000000000000, "end": 1000000000000000000001}"#;
+/// let error = serde_json::from_str::<BruteForce>(checkpoint).unwrap_err();
+/// assert!(error.to_string().starts_with("The string at the position must have less than usize::MAX chars"));
+///
+/// let checkpoint = r#"{"chars": "AB", "raw_current": [], "lengths": [0, 1000]}"#;
+/// let error = serde_json::from_str::<BruteForce>(checkpoint).unwrap_err();
+/// assert!(error.to_string().starts_with("A brute forcer with a length range must be bounded"));
+/// ```
+impl Serialize for BruteForce<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BruteForceRef {
+            chars: &self.chars,
+            raw_current: &self.raw_current,
+            next: self.bounds.map(|bounds| bounds.next),
+            end: self.end(),
+            order: self.order,
+            lengths: self.lengths,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BruteForce<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = BruteForceState::deserialize(deserializer)?;
+        if state.raw_current.iter().any(|&i| i >= state.chars.len()) {
+            return Err(D::Error::custom(
+                "The position must only contain indices of the charset",
+            ));
+        }
+
+        if let Some((min, max)) = state.lengths {
+            if min > max {
+                return Err(D::Error::custom("The length range must not be empty"));
+            }
+        }
+
+        let mut brute_forcer = BruteForce::from_raw(state.chars, state.raw_current, None);
+        brute_forcer.order = state.order;
+        brute_forcer.lengths = state.lengths;
+        let end = match state.end {
+            Some(end) => end,
+            None if state.order == Order::DepthFirst => {
+                return Err(D::Error::custom(
+                    "A brute forcer in depth-first order must be bounded",
+                ));
+            }
+            None if state.lengths.is_some() => {
+                return Err(D::Error::custom(
+                    "A brute forcer with a length range must be bounded",
+                ));
+            }
+            None => return Ok(brute_forcer),
+        };
+
+        let next = match state.next {
+            Some(next) => Some(next),
+            None => brute_forcer.rank_raw(&brute_forcer.raw_current),
+        };
+        let next = next
+            .filter(|&next| next <= end)
+            .ok_or_else(|| D::Error::custom("The position must not be after the end"))?;
+        let base = brute_forcer.chars.len();
+        match state.lengths {
+            // The length range is only used together with bounds within it,
+            // which requires its keyspace to fit in a u128
+            Some((min, max)) => {
+                let first = offset_of_length(base, min);
+                let last = max
+                    .checked_add(1)
+                    .and_then(|len| offset_of_length(base, len));
+                if !matches!(first.zip(last), Some((first, last)) if first <= next && end <= last) {
+                    return Err(D::Error::custom(
+                        "The bounds must be within the length range",
+                    ));
+                }
+            }
+            None if state.order == Order::DepthFirst => {
+                return Err(D::Error::custom(
+                    "A brute forcer in depth-first order must have a length range",
+                ));
+            }
+            None => {}
+        }
+        // With a single char, the index is the length of the next string
+        if base == 1 && next < end && usize::try_from(next).is_err() {
+            return Err(D::Error::custom(
+                "The string at the position must have less than usize::MAX chars",
+            ));
+        }
+
+        brute_forcer.bounds = Some(Bounds { next, end });
+        brute_forcer.seek(next);
+        Ok(brute_forcer)
+    }
+}
+
+/// A bounded brute forcer is serialized like the brute forcer it dereferences to
+impl Serialize for BoundedBruteForce<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.brute_forcer.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BoundedBruteForce<'_> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let brute_forcer = BruteForce::deserialize(deserializer)?;
+        if brute_forcer.bounds.is_none() {
+            return Err(D::Error::custom("The brute forcer must be bounded"));
+        }
+        Ok(BoundedBruteForce { brute_forcer })
+    }
+}
@@ -0,0 +1,225 @@
+//! Brute forcing the subsets of a charset, for which the order of the chars does not matter
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+
+use crate::charset::Charset;
+use crate::error::Error;
+use crate::Bounds;
+
+/// Returns the greatest common divisor of `a` and `b`
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    a
+}
+
+/// Returns the number of combinations of `k` out of `n` elements, or `None` if it does not fit in a `u128`
+pub(crate) fn combinations(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    // Every intermediate result is a binomial coefficient, so `count * (n - i)` is divisible by `i + 1`.
+    // The common divisor of `count` and `i + 1` is divided out first, then the rest of `i + 1`
+    // divides `n - i`, so only a result which does not fit in a `u128` overflows.
+    (0..k.min(n - k)).try_fold(1u128, |count, i| {
+        let divisor = i as u128 + 1;
+        let common = gcd(count, divisor);
+        (count / common).checked_mul((n - i) as u128 / (divisor / common))
+    })
+}
+
+/// Represents a brute-forcing instance which tries every combination of the chars of a charset
+/// within a length range
+///
+/// Every combination is generated once with its chars in the order of the charset.
+/// The combinations are generated shortest first and the combinations of the same length
+/// are sorted by the order of the charset.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::charset::Charset;
+/// use bruteforce::combinations::CombinationBruteForce;
+/// let brute_forcer = CombinationBruteForce::new(Charset::from("ABCD"), 2..=3);
+///
+/// assert_eq!(brute_forcer.len(), 6 + 4);
+/// assert_eq!(
+///     brute_forcer.collect::<Vec<String>>(),
+///     ["AB", "AC", "AD", "BC", "BD", "CD", "ABC", "ABD", "ACD", "BCD"]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CombinationBruteForce<'a> {
+    /// The charset of the combinations
+    chars: Charset<'a>,
+
+    /// This is the current string
+    pub current: String,
+
+    /// Representation of current where each element is an index of the charset, in increasing order
+    raw_current: Vec<usize>,
+
+    /// The remaining part of the keyspace
+    bounds: Bounds,
+}
+
+impl<'a> CombinationBruteForce<'a> {
+    /// Returns a brute forcer which tries every combination within a length range
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char array that contains all chars to be tried
+    /// * `lengths` - The range of the lengths of the combinations, which is cut off at the length of the charset
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or if the keyspace does not fit in a `u128`.
+    pub fn new(charset: Charset<'a>, lengths: RangeInclusive<usize>) -> CombinationBruteForce<'a> {
+        Self::try_new(charset, lengths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which tries every combination within a length range, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyLengthRange`] if the range is empty
+    /// and [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::EmptyLengthRange`]: ../error/enum.Error.html#variant.EmptyLengthRange
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::combinations::CombinationBruteForce;
+    /// use bruteforce::error::Error;
+    ///
+    /// let charset = Charset::by_char_range('\u{0}'..='\u{7f}');
+    /// let brute_forcer = CombinationBruteForce::try_new(charset, 64..=64).unwrap();
+    /// assert_eq!(brute_forcer.remaining(), 23951146041928082866135587776380551750);
+    ///
+    /// let charset = Charset::by_char_range('\u{0}'..='\u{83}');
+    /// let result = CombinationBruteForce::try_new(charset, 66..=66);
+    /// assert_eq!(result.unwrap_err(), Error::KeyspaceTooLarge);
+    /// ```
+    pub fn try_new(
+        charset: Charset<'a>,
+        lengths: RangeInclusive<usize>,
+    ) -> Result<CombinationBruteForce<'a>, Error> {
+        let (min, max) = (*lengths.start(), *lengths.end());
+        if min > max {
+            return Err(Error::EmptyLengthRange);
+        }
+        let n = charset.len();
+        let end = (min..=max.min(n))
+            .try_fold(0u128, |end, k| end.checked_add(combinations(n, k)?))
+            .ok_or(Error::KeyspaceTooLarge)?;
+        Ok(CombinationBruteForce {
+            chars: charset,
+            current: String::default(),
+            raw_current: (0..min).collect(),
+            bounds: Bounds { next: 0, end },
+        })
+    }
+
+    /// Returns the charset of the combinations
+    pub fn chars(&self) -> &Charset<'a> {
+        &self.chars
+    }
+
+    /// Returns the number of combinations left
+    pub fn remaining(&self) -> u128 {
+        self.bounds.end - self.bounds.next
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::combinations::CombinationBruteForce;
+    /// let mut brute_forcer = CombinationBruteForce::new(Charset::from("ABC"), 2..=2);
+    ///
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("AB"));
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("AC"));
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("BC"));
+    /// assert_eq!(brute_forcer.try_raw_next(), None);
+    /// ```
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        if self.bounds.next == self.bounds.end {
+            return None;
+        }
+        self.bounds.next += 1;
+
+        let chars = &self.chars;
+        self.current.clear();
+        self.current
+            .extend(self.raw_current.iter().map(|&i| chars[i]));
+
+        // Increment the last position which is not at its maximum
+        // and move the positions after it right behind it
+        let raw = &mut self.raw_current;
+        let (n, len) = (chars.len(), raw.len());
+        match (0..len)
+            .rev()
+            .find(|&position| raw[position] < n - len + position)
+        {
+            Some(position) => {
+                raw[position] += 1;
+                for next in position + 1..len {
+                    raw[next] = raw[next - 1] + 1;
+                }
+            }
+            // Every combination of this length was tried, so the next one is longer
+            None => *raw = (0..len + 1).collect(),
+        }
+
+        Some(&self.current)
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+}
+
+impl<'a> Iterator for CombinationBruteForce<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`CombinationBruteForce::remaining`] in this case.
+///
+/// [`CombinationBruteForce::remaining`]: struct.CombinationBruteForce.html#method.remaining
+impl ExactSizeIterator for CombinationBruteForce<'_> {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::hashcat::ParseMaskError;
+
+/// The error type of the fallible functions of this crate
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// use bruteforce::error::Error;
+///
+/// let result = BruteForce::try_new_by_start_string(Charset::from("ABC"), "ABD".to_string());
+/// assert_eq!(result.unwrap_err(), Error::UnknownChar('D'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A charset or an alphabet does not contain any element
+    EmptyCharset,
+
+    /// A string contains a char which does not exist in the charset
+    UnknownChar(char),
+
+    /// The maximum length of a length range is less than its minimum length
+    EmptyLengthRange,
+
+    /// The start of an index range is greater than its end
+    InvalidIndexRange,
+
+    /// The number of strings does not fit in a `u128`
+    KeyspaceTooLarge,
+
+    /// The operation requires a brute forcer with a length range
+    NoLengthRange,
+
+    /// The maximum length is greater than the capacity of an allocation-free brute forcer
+    CapacityExceeded,
+
+    /// A mask could not be parsed
+    ///
+    /// The message does not repeat the reason, which is the [`source`] of the error.
+    ///
+    /// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+    #[cfg(feature = "alloc")]
+    ParseMask(ParseMaskError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Error::EmptyCharset => write!(fmt, "The charset must contain at least one character"),
+            Error::UnknownChar(c) => {
+                write!(fmt, "The character `{}` does not exist in the charset", c)
+            }
+            Error::EmptyLengthRange => write!(fmt, "The length range must not be empty"),
+            Error::InvalidIndexRange => write!(
+                fmt,
+                "The start of the index range must not be greater than its end"
+            ),
+            Error::KeyspaceTooLarge => write!(fmt, "The keyspace must fit in a u128"),
+            Error::NoLengthRange => write!(fmt, "The brute forcer must have a length range"),
+            Error::CapacityExceeded => write!(
+                fmt,
+                "The maximum length must not be greater than the capacity"
+            ),
+            #[cfg(feature = "alloc")]
+            Error::ParseMask(_) => write!(fmt, "The mask could not be parsed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "alloc")]
+            Error::ParseMask(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseMaskError> for Error {
+    fn from(error: ParseMaskError) -> Self {
+        Error::ParseMask(error)
+    }
+}
@@ -0,0 +1,161 @@
+//! Brute forcing strings of a fixed length without any heap allocation
+//!
+//! [`FixedBruteForce`] only uses `core`, so it is suitable for targets without an allocator.
+//!
+//! [`FixedBruteForce`]: struct.FixedBruteForce.html
+
+use core::convert::TryFrom;
+
+#[cfg(feature = "alloc")]
+use crate::charset::Charset;
+use crate::error::Error;
+
+/// Represents a brute-forcing instance which yields arrays of a fixed length
+///
+/// The elements can be chars, bytes or any other `Copy` type. Like [`BruteForce`],
+/// the last position changes fastest.
+///
+/// [`BruteForce`]: ../struct.BruteForce.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::fixed::FixedBruteForce;
+///
+/// let brute_forcer = FixedBruteForce::<u8, 4>::new(b"0123456789");
+/// assert_eq!(brute_forcer.len(), 10_000);
+/// assert!(brute_forcer.clone().any(|code| &code == b"4711"));
+/// assert_eq!(brute_forcer.last(), Some(*b"9999"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixedBruteForce<'a, T, const N: usize> {
+    /// The elements to be tried at every position
+    alphabet: &'a [T],
+
+    /// The index of the alphabet at every position
+    indices: [usize; N],
+
+    /// Whether every array was yielded
+    exhausted: bool,
+}
+
+impl<'a, T: Copy, const N: usize> FixedBruteForce<'a, T, N> {
+    /// Returns a brute forcer which tries every array of length `N`
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - A slice that contains all elements to be tried
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet is empty.
+    pub fn new(alphabet: &'a [T]) -> FixedBruteForce<'a, T, N> {
+        Self::try_new(alphabet).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which tries every array of length `N`, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyCharset`] if the alphabet is empty.
+    ///
+    /// [`Error::EmptyCharset`]: ../error/enum.Error.html#variant.EmptyCharset
+    pub fn try_new(alphabet: &'a [T]) -> Result<FixedBruteForce<'a, T, N>, Error> {
+        if alphabet.is_empty() {
+            return Err(Error::EmptyCharset);
+        }
+        Ok(FixedBruteForce {
+            alphabet,
+            indices: [0; N],
+            exhausted: false,
+        })
+    }
+
+    /// Returns the alphabet
+    pub fn alphabet(&self) -> &'a [T] {
+        self.alphabet
+    }
+
+    /// Returns the number of arrays left, or `None` if it does not fit in a `u128`
+    pub fn remaining(&self) -> Option<u128> {
+        if self.exhausted {
+            return Some(0);
+        }
+        let base = self.alphabet.len() as u128;
+        let (total, tried) =
+            self.indices
+                .iter()
+                .try_fold((1u128, 0u128), |(total, tried), &i| {
+                    Some((
+                        total.checked_mul(base)?,
+                        tried.checked_mul(base)? + i as u128,
+                    ))
+                })?;
+        Some(total - tried)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, const N: usize> FixedBruteForce<'a, char, N> {
+    /// Returns a brute forcer which tries every array of length `N` of the chars of a charset
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::fixed::FixedBruteForce;
+    ///
+    /// let charset = Charset::from("AB");
+    /// let mut brute_forcer = FixedBruteForce::<char, 2>::from_charset(&charset);
+    /// assert_eq!(brute_forcer.nth(2), Some(['B', 'A']));
+    /// ```
+    pub fn from_charset(charset: &'a Charset) -> FixedBruteForce<'a, char, N> {
+        Self::new(charset.as_slice())
+    }
+}
+
+impl<T: Copy, const N: usize> Iterator for FixedBruteForce<'_, T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<[T; N]> {
+        if self.exhausted {
+            return None;
+        }
+        let alphabet = self.alphabet;
+        let indices = &mut self.indices;
+        let current = core::array::from_fn(|position| alphabet[indices[position]]);
+
+        // "Add" 1 to the indices, the last position is the least significant one
+        self.exhausted = true;
+        for i in indices.iter_mut().rev() {
+            if *i + 1 < alphabet.len() {
+                *i += 1;
+                self.exhausted = false;
+                break;
+            }
+            *i = 0;
+        }
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining().map(usize::try_from) {
+            Some(Ok(remaining)) => (remaining, Some(remaining)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`FixedBruteForce::remaining`] in this case.
+///
+/// [`FixedBruteForce::remaining`]: struct.FixedBruteForce.html#method.remaining
+impl<T: Copy, const N: usize> ExactSizeIterator for FixedBruteForce<'_, T, N> {
+    fn len(&self) -> usize {
+        self.remaining()
+            .and_then(|remaining| usize::try_from(remaining).ok())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
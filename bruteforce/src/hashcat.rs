@@ -0,0 +1,113 @@
+//! Support for the mask syntax of [hashcat](https://hashcat.net/wiki/doku.php?id=mask_attack)
+//!
+//! A mask describes the charset of every position of the strings:
+//!
+//! | Placeholder | Charset |
+//! |-------------|---------|
+//! | `?l` | `abcdefghijklmnopqrstuvwxyz` |
+//! | `?u` | `ABCDEFGHIJKLMNOPQRSTUVWXYZ` |
+//! | `?d` | `0123456789` |
+//! | `?h` | `0123456789abcdef` |
+//! | `?H` | `0123456789ABCDEF` |
+//! | `?s` | `` !"#$%&'()*+,-./:;<=>?@[\]^_`{\|}~`` and the space |
+//! | `?a` | `?l?u?d?s` |
+//! | `?1` to `?4` | The custom charsets `-1` to `-4` |
+//! | `??` | A literal `?` |
+//!
+//! Every other char stands for itself.
+//!
+//! The placeholder `?b` of hashcat stands for the bytes `0x00` to `0xff`. The bytes from `0x80` on
+//! are not chars and would be encoded as two bytes in UTF-8, so it is rejected.
+//! Use a [`ByteBruteForce`] to brute force bytes instead.
+//!
+//! [`ByteBruteForce`]: ../bytes/struct.ByteBruteForce.html
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::str::FromStr;
+
+use crate::charset::Charset;
+use crate::error::Error;
+use crate::mask::MaskBruteForce;
+
+pub use bruteforce_hashcat::{ParseMaskError, ParseMaskErrorKind, MAX_CUSTOM_CHARSETS};
+
+impl MaskBruteForce<'static> {
+    /// Returns a brute forcer by a hashcat mask
+    ///
+    /// See the [`hashcat`] module for the syntax.
+    ///
+    /// [`hashcat`]: ../hashcat/index.html
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The hashcat mask, e.g. `?u?l?l?d`
+    /// * `custom_charsets` - The definitions of the custom charsets `-1` to `-4`, e.g. `?l?d`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ParseMask`] if the mask or a custom charset could not be parsed
+    /// and [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::ParseMask`]: ../error/enum.Error.html#variant.ParseMask
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::error::Error;
+    /// use bruteforce::hashcat::ParseMaskErrorKind;
+    /// use bruteforce::mask::MaskBruteForce;
+    ///
+    /// let brute_forcer = MaskBruteForce::from_hashcat("?u?1??", &["?dx"]).unwrap();
+    /// assert_eq!(brute_forcer.len(), 26 * 11);
+    /// assert_eq!(brute_forcer.last(), Some("Zx?".to_string()));
+    ///
+    /// match MaskBruteForce::from_hashcat("?u?x", &[]) {
+    ///     Err(Error::ParseMask(error)) => {
+    ///         assert_eq!(error.column, 3);
+    ///         assert_eq!(error.kind, ParseMaskErrorKind::UnknownPlaceholder('x'));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// match MaskBruteForce::from_hashcat("?d?b", &[]) {
+    ///     Err(Error::ParseMask(error)) => assert_eq!(error.kind, ParseMaskErrorKind::BytePlaceholder),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// let result = MaskBruteForce::from_hashcat("?1", &["a", "b", "c", "d", "e"]);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(Error::ParseMask(error)) if error.kind == ParseMaskErrorKind::TooManyCustomCharsets
+    /// ));
+    /// ```
+    pub fn from_hashcat(
+        mask: &str,
+        custom_charsets: &[&str],
+    ) -> Result<MaskBruteForce<'static>, Error> {
+        let charsets = bruteforce_hashcat::parse(mask, custom_charsets)?
+            .into_iter()
+            .map(Charset::from)
+            .collect();
+        MaskBruteForce::try_new(charsets)
+    }
+}
+
+/// Parses a hashcat mask without custom charsets
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::mask::MaskBruteForce;
+///
+/// let brute_forcer: MaskBruteForce = "?d?d?d?d".parse().unwrap();
+/// assert_eq!(brute_forcer.len(), 10_000);
+/// ```
+impl FromStr for MaskBruteForce<'static> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MaskBruteForce::from_hashcat(s, &[])
+    }
+}
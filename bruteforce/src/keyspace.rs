@@ -0,0 +1,206 @@
+//! Computing the size of a keyspace and the time to try all of its strings
+//!
+//! The size is exact even if it does not fit in a `u128`, so it can be checked
+//! before a brute forcer is created.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bruteforce::charset::Charset;
+//! use bruteforce::keyspace::KeyspaceSize;
+//! use std::time::Duration;
+//!
+//! let size = KeyspaceSize::of_charset(&Charset::from("0123456789"), 1..=8);
+//! assert_eq!(size.to_u128(), Some(111_111_110));
+//! assert_eq!(size.time_to_exhaust(1_000_000.0), Some(Duration::from_secs_f64(111.11111)));
+//!
+//! let size = KeyspaceSize::of_charset(&Charset::from("0123456789"), 40..=40);
+//! assert_eq!(size.to_u128(), None);
+//! assert_eq!(size.to_string(), format!("1{}", "0".repeat(40)));
+//! ```
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use crate::charset::Charset;
+
+/// The exact number of strings of a keyspace, which may not fit in a `u128`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyspaceSize {
+    /// The 64 bit digits of the size starting with the least significant one, without leading zeros
+    limbs: Vec<u64>,
+}
+
+impl KeyspaceSize {
+    /// Returns the number of strings of a charset within a length range
+    ///
+    /// This is the length of [`BruteForce::new_bounded`].
+    ///
+    /// [`BruteForce::new_bounded`]: ../struct.BruteForce.html#method.new_bounded
+    pub fn of_charset(charset: &Charset, lengths: RangeInclusive<usize>) -> KeyspaceSize {
+        let mut size = KeyspaceSize::from(0);
+        let mut power = KeyspaceSize::from(1);
+        for len in 0..=*lengths.end() {
+            if len >= *lengths.start() {
+                size.add(&power);
+            }
+            power.mul(charset.len() as u64);
+        }
+        size
+    }
+
+    /// Returns the number of strings of a mask with one charset for every position
+    ///
+    /// This is the length of [`MaskBruteForce::new`].
+    ///
+    /// [`MaskBruteForce::new`]: ../mask/struct.MaskBruteForce.html#method.new
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::keyspace::KeyspaceSize;
+    ///
+    /// let upper = Charset::by_char_range('A'..='Z');
+    /// let digit = Charset::by_char_range('0'..='9');
+    /// let size = KeyspaceSize::of_mask(&[upper, digit.clone(), digit]);
+    /// assert_eq!(size.to_u128(), Some(2600));
+    /// ```
+    pub fn of_mask(charsets: &[Charset]) -> KeyspaceSize {
+        let mut size = KeyspaceSize::from(1);
+        for charset in charsets {
+            size.mul(charset.len() as u64);
+        }
+        size
+    }
+
+    /// Returns the size as `u128`, or `None` if it does not fit
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(u128::from(low)),
+            [low, high] => Some(u128::from(high) << 64 | u128::from(low)),
+            _ => None,
+        }
+    }
+
+    /// Returns the size as `f64`, which may be rounded
+    pub fn to_f64(&self) -> f64 {
+        self.limbs.iter().rev().fold(0.0, |value, &limb| {
+            value * 18_446_744_073_709_551_616.0 + limb as f64
+        })
+    }
+
+    /// Returns the estimated time to try every string at a rate of strings per second
+    ///
+    /// The rate can be measured with a short run, e.g. by `SearchResult::rate`.
+    /// Returns `None` if the rate is not positive or the time does not fit in a `Duration`.
+    pub fn time_to_exhaust(&self, rate: f64) -> Option<Duration> {
+        if rate.is_nan() || rate <= 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(self.to_f64() / rate).ok()
+    }
+
+    /// Multiplies the size by a small factor
+    fn mul(&mut self, factor: u64) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let product = u128::from(*limb) * u128::from(factor) + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+        if factor == 0 {
+            self.limbs.clear();
+        }
+    }
+
+    /// Adds another size
+    fn add(&mut self, other: &KeyspaceSize) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+        let mut carry = false;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let (sum, overflow1) = limb.overflowing_add(other.limbs.get(i).copied().unwrap_or(0));
+            let (sum, overflow2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = overflow1 || overflow2;
+        }
+        if carry {
+            self.limbs.push(1);
+        }
+    }
+
+    /// Divides the size by a small divisor and returns the remainder
+    fn div_rem(&mut self, divisor: u64) -> u64 {
+        let mut remainder = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let dividend = u128::from(remainder) << 64 | u128::from(*limb);
+            *limb = (dividend / u128::from(divisor)) as u64;
+            remainder = (dividend % u128::from(divisor)) as u64;
+        }
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        remainder
+    }
+}
+
+impl From<u128> for KeyspaceSize {
+    fn from(size: u128) -> Self {
+        let mut limbs = vec![size as u64, (size >> 64) as u64];
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        KeyspaceSize { limbs }
+    }
+}
+
+impl PartialOrd for KeyspaceSize {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KeyspaceSize {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // There are no leading zeros, so more limbs mean a greater size
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+/// The size is displayed in decimal
+impl fmt::Display for KeyspaceSize {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        // Split the size into chunks of 19 decimal digits, starting with the least significant one
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(rest.div_rem(CHUNK));
+            if rest.limbs.is_empty() {
+                break;
+            }
+        }
+
+        let mut chunks = chunks.iter().rev();
+        write!(
+            fmt,
+            "{}",
+            chunks.next().expect("Bug: There is at least one chunk")
+        )?;
+        chunks.try_for_each(|chunk| write!(fmt, "{:019}", chunk))
+    }
+}
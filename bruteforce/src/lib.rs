@@ -1,6 +1,7 @@
 //! This is the documentation for the no-std compatible `bruteforce` crate
 
 #![crate_name = "bruteforce"]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(not(feature = "std"))]
 extern crate no_std_compat as std;
@@ -8,30 +9,316 @@ extern crate no_std_compat as std;
 #[cfg(feature = "bruteforce-macros")]
 extern crate bruteforce_macros;
 
+#[cfg(feature = "bruteforce-macros")]
+pub use bruteforce_macros::{charset, mask};
+
+/// Items used by the code generated by the macros
+#[cfg(feature = "bruteforce-macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use std::string::{String, ToString};
+}
+
+#[cfg(feature = "alloc")]
+pub mod bytes;
+#[cfg(feature = "alloc")]
 pub mod charset;
+#[cfg(feature = "serde")]
+mod checkpoint;
+#[cfg(feature = "alloc")]
+pub mod combinations;
+#[cfg(feature = "constants")]
+pub mod constants;
+pub mod error;
+pub mod fixed;
+#[cfg(feature = "alloc")]
+pub mod hashcat;
+#[cfg(feature = "alloc")]
+pub mod keyspace;
+#[cfg(feature = "alloc")]
+pub mod mask;
+#[cfg(feature = "alloc")]
+pub mod order;
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "alloc")]
+pub mod permutations;
+#[cfg(feature = "std")]
+pub mod progress;
+#[cfg(feature = "alloc")]
+pub mod sample;
+#[cfg(feature = "std")]
+pub mod search;
+#[cfg(feature = "alloc")]
+pub mod shuffle;
+pub mod slice;
+#[cfg(feature = "futures")]
+pub mod stream;
 
+#[cfg(feature = "alloc")]
+use std::convert::TryFrom;
+#[cfg(feature = "alloc")]
+use std::ops::{Deref, Range, RangeInclusive};
 #[cfg(feature = "generators")]
 use std::ops::{Generator, GeneratorState};
 #[cfg(feature = "generators")]
 use std::pin::Pin;
+#[cfg(feature = "alloc")]
 use std::prelude::v1::*;
 
+#[cfg(feature = "alloc")]
 use charset::Charset;
+#[cfg(feature = "alloc")]
+use error::Error;
+#[cfg(feature = "alloc")]
+use order::Order;
 
 /// Represents a brute-forcing instance
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
 pub struct BruteForce<'a> {
     /// Represents the charset of the brute-forcer
     pub chars: Charset<'a>,
 
     /// This is the current string
+    ///
+    /// It is a copy of the string returned by `raw_next`, so modifying it does not change the next strings.
     pub current: String,
 
+    /// The string returned by `raw_next`, of which only the changed suffix is rewritten
+    buffer: String,
+
+    /// The UTF-8 encoding of every char of the charset
+    encoded: Vec<String>,
+
     /// Reversed representation of current where each element is an index of charset
     raw_current: Vec<usize>,
+
+    /// The number of positions of `raw_current` which changed since `buffer` was written,
+    /// or `usize::MAX` if `buffer` must be rebuilt
+    changed: usize,
+
+    /// The remaining part of the keyspace, if the brute forcer is bounded
+    bounds: Option<Bounds>,
+
+    /// The order of the strings
+    order: Order,
+
+    /// The minimum and maximum length, if the brute forcer was created with a length range
+    lengths: Option<(usize, usize)>,
+}
+
+/// Represents the remaining keyspace of a bounded brute forcer
+///
+/// Both values are global indices, where index 0 is the empty string,
+/// followed by all strings of length 1, length 2 and so on.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds {
+    /// The index of the next string to be returned
+    next: u128,
+
+    /// The index after the last string to be returned
+    end: u128,
+}
+
+#[cfg(feature = "alloc")]
+impl Bounds {
+    /// Returns the index range of shard `index` out of `count` contiguous, disjoint shards
+    fn shard(&self, index: usize, count: usize) -> Range<u128> {
+        assert!(
+            index < count,
+            "The shard index must be less than the shard count"
+        );
+        let (index, count) = (index as u128, count as u128);
+        let remaining = self.end - self.next;
+        let (size, extra) = (remaining / count, remaining % count);
+        // The first `extra` shards contain one more string than the others
+        let start = self.next + index * size + index.min(extra);
+        let end = start + size + u128::from(index < extra);
+        start..end
+    }
+}
+
+/// Returns the number of strings which are shorter than `len`
+///
+/// This is the global index of the first string of length `len`,
+/// or `None` if it does not fit in a `u128`.
+fn offset_of_length(base: usize, len: usize) -> Option<u128> {
+    let base = base as u128;
+    let mut offset: u128 = 0;
+    let mut power: u128 = 1;
+    for i in 0..len {
+        if i > 0 {
+            power = power.checked_mul(base)?;
+        }
+        offset = offset.checked_add(power)?;
+    }
+    Some(offset)
 }
 
+/// "Adds" 1 to a reversed representation like `raw_current`
+///
+/// `base` returns the number of chars at a position of the reversed representation.
+/// Returns the number of changed positions, or `None` if the addition carried over the last position.
+#[inline]
+fn increment(raw: &mut [usize], base: impl Fn(usize) -> usize) -> Option<usize> {
+    for (position, i) in raw.iter_mut().enumerate() {
+        if *i == base(position) - 1 {
+            *i = 0;
+        } else {
+            *i += 1;
+            return Some(position + 1);
+        }
+    }
+    None
+}
+
+/// Converts a global index into the reversed representation used by `raw_current`
+///
+/// The index is treated as a mixed-radix number: first the length is found
+/// by skipping all shorter strings, then the rest is split into digits of base `base`.
+///
+/// # Panics
+///
+/// Panics if `base` is 1 and the index does not fit in a `usize`,
+/// because the string would be longer than any string in memory.
+#[cfg(feature = "alloc")]
+fn unrank(base: usize, index: u128) -> Vec<usize> {
+    if base == 1 {
+        // Every length has exactly one string, so the index is the length
+        let len = usize::try_from(index)
+            .expect("The string at the index must have less than usize::MAX chars");
+        return vec![0; len];
+    }
+
+    let base = base as u128;
+    let mut rest = index;
+    let mut len = 0;
+    let mut power: u128 = 1;
+    while rest >= power {
+        rest -= power;
+        len += 1;
+        power = match power.checked_mul(base) {
+            Some(power) => power,
+            // base^len is larger than any u128, so the length is found
+            None => break,
+        };
+    }
+
+    (0..len)
+        .map(|_| {
+            let digit = rest % base;
+            rest /= base;
+            digit as usize
+        })
+        .collect()
+}
+
+/// Converts a string into the reversed representation used by `raw_current`
+#[cfg(feature = "alloc")]
+fn raw_from_str(charset: &Charset, s: &str) -> Result<Vec<usize>, Error> {
+    s.chars()
+        .rev()
+        .map(|c1| {
+            charset
+                .iter()
+                .position(|&c2| c1 == c2)
+                .ok_or(Error::UnknownChar(c1))
+        })
+        .collect()
+}
+
+/// Converts the reversed representation used by `raw_current` into a global index
+///
+/// Returns `None` if the index does not fit in a `u128`.
+fn rank(base: usize, raw: &[usize]) -> Option<u128> {
+    let value = raw.iter().rev().try_fold(0u128, |value, &digit| {
+        value.checked_mul(base as u128)?.checked_add(digit as u128)
+    })?;
+    offset_of_length(base, raw.len())?.checked_add(value)
+}
+
+#[cfg(feature = "alloc")]
 impl<'a> BruteForce<'a> {
+    /// Returns a brute forcer with the given state
+    fn from_raw(
+        charset: Charset<'a>,
+        raw_current: Vec<usize>,
+        bounds: Option<Bounds>,
+    ) -> BruteForce<'a> {
+        let encoded = charset.iter().map(|c| c.to_string()).collect();
+        BruteForce {
+            chars: charset,
+            current: String::default(),
+            buffer: String::default(),
+            encoded,
+            raw_current,
+            changed: usize::MAX,
+            bounds,
+            order: Order::default(),
+            lengths: None,
+        }
+    }
+
+    /// Moves the brute forcer to a global index within its bounds
+    fn seek(&mut self, index: u128) {
+        if let Some(bounds) = &mut self.bounds {
+            bounds.next = index;
+            if index == bounds.end {
+                // There is no string at the end, so the position does not matter
+                return;
+            }
+        }
+        self.raw_current = self.unrank_raw(index);
+        self.changed = usize::MAX;
+    }
+
+    /// Returns a copy of the brute forcer which only tries the strings within a global index range
+    fn with_bounds(&self, indices: Range<u128>) -> BoundedBruteForce<'a> {
+        let mut brute_forcer = self.clone();
+        brute_forcer.bounds = Some(Bounds {
+            next: indices.start,
+            end: indices.end,
+        });
+        brute_forcer.seek(indices.start);
+        BoundedBruteForce { brute_forcer }
+    }
+
+    /// Converts a global index into the reversed representation of the string in the current order
+    fn unrank_raw(&self, index: u128) -> Vec<usize> {
+        match (self.order, self.lengths) {
+            (Order::DepthFirst, Some(lengths)) => {
+                let start = offset_of_length(self.chars.len(), lengths.0)
+                    .expect("Bug: The keyspace is checked by the constructor");
+                let rank = index
+                    .checked_sub(start)
+                    .expect("The index must be within the lengths of the brute forcer");
+                order::depth_first_unrank(self.chars.len(), rank, lengths)
+            }
+            _ => unrank(self.chars.len(), index),
+        }
+    }
+
+    /// Converts the reversed representation of a string in the current order into a global index
+    fn rank_raw(&self, raw: &[usize]) -> Option<u128> {
+        match (self.order, self.lengths) {
+            (Order::DepthFirst, Some(lengths)) => {
+                let start = offset_of_length(self.chars.len(), lengths.0)?;
+                order::depth_first_rank(self.chars.len(), raw, lengths)?.checked_add(start)
+            }
+            _ => rank(self.chars.len(), raw),
+        }
+    }
+
+    /// Converts the reversed representation of a string in the current order into the string
+    fn raw_to_string(&self, raw: &[usize]) -> String {
+        match self.order {
+            Order::Colexicographic => raw.iter().map(|&i| self.chars[i]).collect(),
+            _ => raw.iter().rev().map(|&i| self.chars[i]).collect(),
+        }
+    }
+
     /// Returns a brute forcer with default settings
     ///
     /// # Arguments
@@ -55,12 +342,8 @@ impl<'a> BruteForce<'a> {
     /// }
     /// ```
     pub fn new(charset: Charset) -> BruteForce {
-        BruteForce {
-            chars: charset,
-            current: String::default(),
-            // Maybe the answer is an empty string?
-            raw_current: vec![],
-        }
+        // Maybe the answer is an empty string?
+        BruteForce::from_raw(charset, vec![], None)
     }
 
     /// Returns a brute forcer skipping some letters
@@ -88,11 +371,84 @@ impl<'a> BruteForce<'a> {
     /// }
     /// ```
     pub fn new_at(charset: Charset, start: usize) -> BruteForce {
-        BruteForce {
-            chars: charset,
-            current: String::default(),
-            raw_current: (0..start).map(|_| 0).collect::<Vec<usize>>(),
+        BruteForce::from_raw(charset, vec![0; start], None)
+    }
+
+    /// Returns a brute forcer which only tries strings within a length range
+    ///
+    /// Unlike the other constructors, the brute forcer stops after the last string
+    /// of the maximum length, so it is a [`BoundedBruteForce`] with an exact length.
+    ///
+    /// [`BoundedBruteForce`]: struct.BoundedBruteForce.html
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char array that contains all chars to be tried
+    /// * `lengths` - The minimum and maximum length of the strings
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or if the keyspace does not fit in a `u128`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// const CHARSET: Charset = Charset::new(&['A', 'B', 'C']);
+    /// let brute_forcer = BruteForce::new_bounded(CHARSET, 1..=2);
+    ///
+    /// assert_eq!(brute_forcer.len(), 3 + 3 * 3);
+    /// assert_eq!(brute_forcer.last(), Some("CC".to_string()));
+    /// ```
+    pub fn new_bounded(
+        charset: Charset<'a>,
+        lengths: RangeInclusive<usize>,
+    ) -> BoundedBruteForce<'a> {
+        Self::try_new_bounded(charset, lengths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which only tries strings within a length range, or an error
+    ///
+    /// See [`new_bounded`] for details.
+    ///
+    /// [`new_bounded`]: #method.new_bounded
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyLengthRange`] if the range is empty
+    /// and [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::EmptyLengthRange`]: error/enum.Error.html#variant.EmptyLengthRange
+    /// [`Error::KeyspaceTooLarge`]: error/enum.Error.html#variant.KeyspaceTooLarge
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::error::Error;
+    ///
+    /// let result = BruteForce::try_new_bounded(Charset::from("ABC"), 0..=100);
+    /// assert_eq!(result.unwrap_err(), Error::KeyspaceTooLarge);
+    /// ```
+    pub fn try_new_bounded(
+        charset: Charset<'a>,
+        lengths: RangeInclusive<usize>,
+    ) -> Result<BoundedBruteForce<'a>, Error> {
+        let (min, max) = (*lengths.start(), *lengths.end());
+        if min > max {
+            return Err(Error::EmptyLengthRange);
         }
+        let next = offset_of_length(charset.len(), min);
+        let end = max
+            .checked_add(1)
+            .and_then(|len| offset_of_length(charset.len(), len));
+        let (next, end) = next.zip(end).ok_or(Error::KeyspaceTooLarge)?;
+        let bounds = Bounds { next, end };
+        let mut brute_forcer = BruteForce::from_raw(charset, vec![0; min], Some(bounds));
+        brute_forcer.lengths = Some((min, max));
+        Ok(BoundedBruteForce { brute_forcer })
     }
 
     /// Returns a brute forcer skipping some text
@@ -120,55 +476,661 @@ impl<'a> BruteForce<'a> {
     /// }
     /// ```
     pub fn new_by_start_string(charset: Charset, start_string: String) -> BruteForce {
-        BruteForce {
-            current: String::default(),
-            raw_current: start_string
-                .chars()
-                .rev()
-                .map(|c1| charset.iter().position(|&c2| c1 == c2))
-                .collect::<Option<Vec<usize>>>()
-                .expect("characters in start_string must exist in charset"),
-            // assigning charset to chars must happen after it is used by .map()
-            chars: charset,
+        BruteForce::try_new_by_start_string(charset, start_string)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer skipping some text, or an error if the text contains an unknown char
+    ///
+    /// See [`new_by_start_string`] for details.
+    ///
+    /// [`new_by_start_string`]: #method.new_by_start_string
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownChar`] if a char of `start_string` does not exist in the charset.
+    ///
+    /// [`Error::UnknownChar`]: error/enum.Error.html#variant.UnknownChar
+    pub fn try_new_by_start_string(
+        charset: Charset<'a>,
+        start_string: String,
+    ) -> Result<BruteForce<'a>, Error> {
+        let raw_current = raw_from_str(&charset, &start_string)?;
+        Ok(BruteForce::from_raw(charset, raw_current, None))
+    }
+
+    /// Returns a brute forcer starting at a global index
+    ///
+    /// The global index of a string counts all strings before it:
+    /// index 0 is the empty string, followed by all strings of length 1, length 2 and so on.
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char array that contains all chars to be tried
+    /// * `index` - The global index of the first string
+    ///
+    /// # Panics
+    ///
+    /// Panics if the charset contains only one char and the index does not fit in a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// // This is useful to save our brute force progress as a single number
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// const CHARSET: Charset = Charset::new(&['A', 'B', 'C']);
+    /// let mut brute_forcer = BruteForce::new_by_index(CHARSET, 5);
+    ///
+    /// assert_eq!(brute_forcer.next(), Some("AB".to_string()));
+    /// ```
+    pub fn new_by_index(charset: Charset<'a>, index: u128) -> BruteForce<'a> {
+        let raw_current = unrank(charset.len(), index);
+        BruteForce::from_raw(charset, raw_current, None)
+    }
+
+    /// Returns a brute forcer which only tries the strings within a global index range
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char array that contains all chars to be tried
+    /// * `indices` - The global indices of the strings, see [`new_by_index`]
+    ///
+    /// [`new_by_index`]: #method.new_by_index
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the charset
+    /// contains only one char and the start does not fit in a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_by_index_range(Charset::from("ABC"), 2..6);
+    ///
+    /// assert_eq!(brute_forcer.collect::<Vec<String>>(), ["B", "C", "AA", "AB"]);
+    /// ```
+    pub fn new_by_index_range(charset: Charset<'a>, indices: Range<u128>) -> BoundedBruteForce<'a> {
+        Self::try_new_by_index_range(charset, indices).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which only tries the strings within a global index range, or an error
+    ///
+    /// See [`new_by_index_range`] for details.
+    ///
+    /// [`new_by_index_range`]: #method.new_by_index_range
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidIndexRange`] if the start of the range is greater than its end.
+    ///
+    /// [`Error::InvalidIndexRange`]: error/enum.Error.html#variant.InvalidIndexRange
+    pub fn try_new_by_index_range(
+        charset: Charset<'a>,
+        indices: Range<u128>,
+    ) -> Result<BoundedBruteForce<'a>, Error> {
+        if indices.start > indices.end {
+            return Err(Error::InvalidIndexRange);
+        }
+        let raw_current = unrank(charset.len(), indices.start);
+        let bounds = Bounds {
+            next: indices.start,
+            end: indices.end,
+        };
+        let brute_forcer = BruteForce::from_raw(charset, raw_current, Some(bounds));
+        Ok(BoundedBruteForce { brute_forcer })
+    }
+
+    /// Returns the string at a global index
+    ///
+    /// This runs in `O(length)` and does not change the state of the brute forcer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the charset contains only one char and the index does not fit in a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new(Charset::from("ABC"));
+    ///
+    /// assert_eq!(brute_forcer.candidate_at(0), "");
+    /// assert_eq!(brute_forcer.candidate_at(3), "C");
+    /// assert_eq!(brute_forcer.candidate_at(4), "AA");
+    /// ```
+    pub fn candidate_at(&self, index: u128) -> String {
+        self.raw_to_string(&self.unrank_raw(index))
+    }
+
+    /// Returns the global index of a string
+    ///
+    /// Returns `None` if the string contains a char which is not in the charset
+    /// or if the index does not fit in a `u128`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new(Charset::from("ABC"));
+    ///
+    /// assert_eq!(brute_forcer.index_of("AA"), Some(4));
+    /// assert_eq!(brute_forcer.index_of("AD"), None);
+    /// ```
+    pub fn index_of(&self, candidate: &str) -> Option<u128> {
+        let mut raw = raw_from_str(&self.chars, candidate).ok()?;
+        if self.order == Order::Colexicographic {
+            raw.reverse();
+        }
+        self.rank_raw(&raw)
+    }
+
+    /// Returns the global index of the next string
+    ///
+    /// Returns `None` if the index does not fit in a `u128`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new(Charset::from("ABC"));
+    /// brute_forcer.nth(41);
+    ///
+    /// assert_eq!(brute_forcer.index(), Some(42));
+    /// ```
+    pub fn index(&self) -> Option<u128> {
+        match self.bounds {
+            Some(bounds) => Some(bounds.next),
+            None => self.rank_raw(&self.raw_current),
+        }
+    }
+
+    /// Returns the number of strings left, or `None` if the brute forcer is unbounded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 2..=2);
+    /// brute_forcer.next();
+    ///
+    /// assert_eq!(brute_forcer.remaining(), Some(3));
+    /// assert_eq!(BruteForce::new(Charset::from("AB")).remaining(), None);
+    /// ```
+    pub fn remaining(&self) -> Option<u128> {
+        self.bounds.map(|bounds| bounds.end - bounds.next)
+    }
+
+    /// Returns the global index after the last string, or `None` if the brute forcer is unbounded
+    pub fn end(&self) -> Option<u128> {
+        self.bounds.map(|bounds| bounds.end)
+    }
+
+    /// Returns the order of the strings
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Returns the brute forcer generating its strings in another order
+    ///
+    /// The brute forcer keeps its global index, so it tries the same number of strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoLengthRange`] for [`Order::DepthFirst`] if the brute forcer
+    /// was not created by [`new_bounded`], i.e. if it has no length range or no bounds.
+    ///
+    /// [`Error::NoLengthRange`]: error/enum.Error.html#variant.NoLengthRange
+    /// [`Order::DepthFirst`]: order/enum.Order.html#variant.DepthFirst
+    /// [`new_bounded`]: #method.new_bounded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::order::Order;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=2);
+    ///
+    /// let colex = brute_forcer.clone().with_order(Order::Colexicographic).unwrap();
+    /// assert_eq!(colex.collect::<Vec<String>>(), ["A", "B", "AA", "BA", "AB", "BB"]);
+    ///
+    /// let depth_first = brute_forcer.with_order(Order::DepthFirst).unwrap();
+    /// assert_eq!(depth_first.collect::<Vec<String>>(), ["A", "AA", "AB", "B", "BA", "BB"]);
+    /// ```
+    pub fn with_order(mut self, order: Order) -> Result<BruteForce<'a>, Error> {
+        // The depth-first step wraps around at the end of the length range,
+        // so only the bounds stop it
+        if order == Order::DepthFirst && (self.lengths.is_none() || self.bounds.is_none()) {
+            return Err(Error::NoLengthRange);
+        }
+        let index = self.index();
+        self.order = order;
+        // Lexicographic and colexicographic order share the representation,
+        // so an unbounded brute forcer without index does not need to seek
+        if let Some(index) = index {
+            self.seek(index);
         }
+        self.changed = usize::MAX;
+        Ok(self)
     }
 
     /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace of a bounded brute forcer is exhausted.
+    /// Use [`try_raw_next`] for bounded brute forcers instead.
+    ///
+    /// [`try_raw_next`]: #method.try_raw_next
     pub fn raw_next(&mut self) -> &str {
-        let cur = &mut self.current;
-        let chars = &self.chars;
-
-        cur.clear();
-        cur.extend(self.raw_current.iter().rev().map(|&i| {
-            assert!(i < chars.len(), "Bug: Invalid character index");
-            chars[i]
-        }));
-
-        // "Add" 1 to self.raw_current
-        let mut carryover = true;
-        let chars_len_m = self.chars.len() - 1;
-        for i in self.raw_current.iter_mut() {
-            if *i == chars_len_m {
-                *i = 0;
-            } else {
-                *i += 1;
-                carryover = false;
-                break;
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+
+    /// This returns the next element, or `None` if the keyspace of a bounded brute forcer is exhausted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=1);
+    ///
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("A"));
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("B"));
+    /// assert_eq!(brute_forcer.try_raw_next(), None);
+    /// ```
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        self.try_raw_next_with_changes().map(|(current, _)| current)
+    }
+
+    /// This returns the next element and the number of its trailing chars which changed
+    ///
+    /// The number of changed chars is the length of the suffix which differs from the
+    /// previous element, or the whole length if the length changed.
+    /// In other orders than [`Order::Lexicographic`] it is always the whole length.
+    /// This way callers can reuse computations on the unchanged prefix.
+    ///
+    /// [`Order::Lexicographic`]: order/enum.Order.html#variant.Lexicographic
+    ///
+    /// Returns `None` if the keyspace of a bounded brute forcer is exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_by_start_string(Charset::from("AB"), "AB".to_string());
+    ///
+    /// assert_eq!(brute_forcer.try_raw_next_with_changes(), Some(("AB", 2)));
+    /// assert_eq!(brute_forcer.try_raw_next_with_changes(), Some(("BA", 2)));
+    /// assert_eq!(brute_forcer.try_raw_next_with_changes(), Some(("BB", 1)));
+    /// assert_eq!(brute_forcer.try_raw_next_with_changes(), Some(("AAA", 3)));
+    /// ```
+    ///
+    /// The suffix is rewritten in a private buffer, so chars of any width and
+    /// a modified [`current`] do not change the next strings:
+    ///
+    /// [`current`]: #structfield.current
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_at(Charset::from("aé€😀"), 2);
+    ///
+    /// for index in 5..5 + 16 + 64 {
+    ///     let expected = brute_forcer.candidate_at(index);
+    ///     assert_eq!(brute_forcer.raw_next(), expected);
+    /// }
+    ///
+    /// let mut brute_forcer = BruteForce::new_at(Charset::from("AB"), 2);
+    /// brute_forcer.raw_next();
+    /// brute_forcer.current = "ZZ".to_string();
+    /// assert_eq!(brute_forcer.raw_next(), "AB");
+    /// ```
+    pub fn try_raw_next_with_changes(&mut self) -> Option<(&str, usize)> {
+        if let Some(bounds) = &mut self.bounds {
+            if bounds.next == bounds.end {
+                return None;
             }
+            bounds.next += 1;
         }
-        if carryover {
-            self.raw_current.push(0);
+
+        let buffer = &mut self.buffer;
+        let encoded = &self.encoded;
+        let raw = &self.raw_current;
+        let changed = self.changed;
+
+        let suffix = if self.order != Order::Lexicographic || changed > raw.len() {
+            // Other orders do not only change a suffix
+            None
+        } else if changed == 0 {
+            Some(0)
+        } else {
+            // The positions before the last changed one carried over from the last char,
+            // so the length of the old suffix is known without storing it
+            Some(
+                (changed - 1) * encoded[encoded.len() - 1].len()
+                    + encoded[raw[changed - 1] - 1].len(),
+            )
+        };
+
+        let changed = match suffix {
+            Some(suffix) => {
+                buffer.truncate(buffer.len() - suffix);
+                raw[..changed]
+                    .iter()
+                    .rev()
+                    .for_each(|&i| buffer.push_str(&encoded[i]));
+                changed
+            }
+            None => {
+                buffer.clear();
+                match self.order {
+                    Order::Colexicographic => {
+                        raw.iter().for_each(|&i| buffer.push_str(&encoded[i]))
+                    }
+                    _ => raw.iter().rev().for_each(|&i| buffer.push_str(&encoded[i])),
+                }
+                raw.len()
+            }
+        };
+        // The public copy may be modified by the caller, so the next string is not built from it
+        self.current.clear();
+        self.current.push_str(buffer);
+        let chars_len = encoded.len();
+
+        match (self.order, self.lengths) {
+            (Order::DepthFirst, Some(lengths)) => {
+                order::depth_first_step(&mut self.raw_current, chars_len, lengths);
+            }
+            // "Add" 1 to self.raw_current
+            _ => match increment(&mut self.raw_current, |_| chars_len) {
+                Some(changed) => self.changed = changed,
+                None => {
+                    self.raw_current.push(0);
+                    self.changed = usize::MAX;
+                }
+            },
         }
 
-        &self.current
+        Some((&self.current, changed))
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a> Iterator for BruteForce<'a> {
     type Item = String;
 
     fn next(&mut self) -> Option<String> {
-        Some(self.raw_next().to_string())
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<String> {
+        // Skipping is done by index arithmetic instead of generating every string
+        if let Some(index) = self.index() {
+            let mut target = index.saturating_add(n as u128);
+            if let Some(end) = self.end() {
+                target = target.min(end);
+            }
+            self.seek(target);
+            return self.next();
+        }
+
+        for _ in 0..n {
+            self.try_raw_next()?;
+        }
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining().map(usize::try_from) {
+            Some(Ok(remaining)) => (remaining, Some(remaining)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// Represents a brute-forcing instance with a finite keyspace
+///
+/// It is returned by the bounded constructors of [`BruteForce`], like [`new_bounded`].
+/// It dereferences to the brute forcer, and unlike an unbounded brute forcer
+/// it has an exact length and can be consumed from the back.
+///
+/// [`BruteForce`]: struct.BruteForce.html
+/// [`new_bounded`]: struct.BruteForce.html#method.new_bounded
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let mut brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=2);
+///
+/// assert_eq!(brute_forcer.len(), 6);
+/// assert_eq!(brute_forcer.next(), Some("A".to_string()));
+/// assert_eq!(brute_forcer.index(), Some(2));
+/// ```
+///
+/// An unbounded brute forcer has no length:
+///
+/// ```compile_fail
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let brute_forcer = BruteForce::new(Charset::from("AB"));
+///
+/// brute_forcer.len();
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct BoundedBruteForce<'a> {
+    /// The brute forcer, whose bounds are always set
+    brute_forcer: BruteForce<'a>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> BoundedBruteForce<'a> {
+    /// Returns the remaining keyspace
+    fn bounds(&self) -> Bounds {
+        self.brute_forcer
+            .bounds
+            .expect("Bug: A bounded brute forcer has bounds")
+    }
+
+    /// Returns the brute forcer without the guarantee that it is bounded
+    pub fn into_inner(self) -> BruteForce<'a> {
+        self.brute_forcer
+    }
+
+    /// Returns the brute forcer generating its strings in another order
+    ///
+    /// See [`BruteForce::with_order`] for details.
+    ///
+    /// [`BruteForce::with_order`]: struct.BruteForce.html#method.with_order
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoLengthRange`] for [`Order::DepthFirst`] if the brute forcer
+    /// was not created by [`BruteForce::new_bounded`].
+    ///
+    /// [`Error::NoLengthRange`]: error/enum.Error.html#variant.NoLengthRange
+    /// [`Order::DepthFirst`]: order/enum.Order.html#variant.DepthFirst
+    /// [`BruteForce::new_bounded`]: struct.BruteForce.html#method.new_bounded
+    pub fn with_order(self, order: Order) -> Result<BoundedBruteForce<'a>, Error> {
+        let brute_forcer = self.brute_forcer.with_order(order)?;
+        Ok(BoundedBruteForce { brute_forcer })
+    }
+
+    /// Returns one of `count` contiguous, disjoint shards of the remaining keyspace
+    ///
+    /// All shards together contain every remaining string exactly once and their sizes
+    /// differ by at most one. This way every worker can compute its own shard
+    /// knowing only its number and the number of workers.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The number of the shard, starting at 0
+    /// * `count` - The total number of shards
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `count`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("ABC"), 1..=2);
+    /// let shard = brute_forcer.shard(1, 3);
+    ///
+    /// assert_eq!((shard.index(), shard.end()), (Some(5), Some(9)));
+    /// assert_eq!(shard.collect::<Vec<String>>(), ["AB", "AC", "BA", "BB"]);
+    /// ```
+    pub fn shard(&self, index: usize, count: usize) -> BoundedBruteForce<'a> {
+        let range = self.bounds().shard(index, count);
+        self.brute_forcer.with_bounds(range)
+    }
+
+    /// Splits the remaining keyspace into `count` contiguous, disjoint shards
+    ///
+    /// See [`shard`] for details.
+    ///
+    /// [`shard`]: #method.shard
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("ABC"), 1..=2);
+    /// let shards = brute_forcer.shards(5);
+    ///
+    /// assert_eq!(shards.len(), 5);
+    /// assert_eq!(shards.into_iter().flatten().count(), 12);
+    /// ```
+    pub fn shards(&self, count: usize) -> Vec<BoundedBruteForce<'a>> {
+        (0..count).map(|index| self.shard(index, count)).collect()
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.brute_forcer.raw_next()
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        self.brute_forcer.try_raw_next()
+    }
+
+    /// This returns the next element and the number of its trailing chars which changed
+    ///
+    /// See [`BruteForce::try_raw_next_with_changes`] for details.
+    ///
+    /// [`BruteForce::try_raw_next_with_changes`]: struct.BruteForce.html#method.try_raw_next_with_changes
+    pub fn try_raw_next_with_changes(&mut self) -> Option<(&str, usize)> {
+        self.brute_forcer.try_raw_next_with_changes()
+    }
+}
+
+/// Only reading methods are available, so the brute forcer stays bounded
+#[cfg(feature = "alloc")]
+impl<'a> Deref for BoundedBruteForce<'a> {
+    type Target = BruteForce<'a>;
+
+    fn deref(&self) -> &BruteForce<'a> {
+        &self.brute_forcer
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for BoundedBruteForce<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.brute_forcer.next()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<String> {
+        self.brute_forcer.nth(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.brute_forcer.size_hint()
+    }
+}
+
+/// The strings are generated by index arithmetic, so the front and the back
+/// do not share any state and meet in the middle.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let mut brute_forcer = BruteForce::new_bounded(Charset::from("0123456789"), 4..=4);
+///
+/// assert_eq!(brute_forcer.next_back(), Some("9999".to_string()));
+/// assert_eq!(brute_forcer.nth_back(9), Some("9989".to_string()));
+/// assert_eq!(brute_forcer.next(), Some("0000".to_string()));
+/// assert_eq!(brute_forcer.len(), 10_000 - 12);
+/// ```
+///
+/// An unbounded brute forcer has no last string, so it cannot be reversed:
+///
+/// ```compile_fail
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let brute_forcer = BruteForce::new(Charset::from("AB"));
+///
+/// brute_forcer.rev();
+/// ```
+#[cfg(feature = "alloc")]
+impl DoubleEndedIterator for BoundedBruteForce<'_> {
+    fn next_back(&mut self) -> Option<String> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<String> {
+        let bounds = self
+            .brute_forcer
+            .bounds
+            .as_mut()
+            .expect("Bug: A bounded brute forcer has bounds");
+        let remaining = bounds.end - bounds.next;
+        if remaining <= n as u128 {
+            bounds.end = bounds.next;
+            return None;
+        }
+        bounds.end -= n as u128 + 1;
+        let index = bounds.end;
+        Some(self.brute_forcer.candidate_at(index))
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`BruteForce::remaining`] in this case.
+///
+/// [`BruteForce::remaining`]: struct.BruteForce.html#method.remaining
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for BoundedBruteForce<'_> {
+    fn len(&self) -> usize {
+        let bounds = self.bounds();
+        usize::try_from(bounds.end - bounds.next)
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
     }
 }
 
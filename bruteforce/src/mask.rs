@@ -0,0 +1,175 @@
+use std::prelude::v1::*; // needed for std-compat
+
+use std::convert::TryFrom;
+
+use crate::charset::Charset;
+use crate::error::Error;
+use crate::{increment, Bounds};
+
+/// Represents a brute-forcing instance with an own charset for every position
+///
+/// This is useful if the strings follow a known pattern, e.g. a password policy.
+/// The strings are generated in the same order as by [`BruteForce`]:
+/// the last position changes fastest.
+///
+/// [`BruteForce`]: ../struct.BruteForce.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::charset::Charset;
+/// use bruteforce::mask::MaskBruteForce;
+///
+/// // An uppercase letter followed by two digits
+/// let brute_forcer = MaskBruteForce::new(vec![
+///     Charset::by_char_range('A'..='Z'),
+///     Charset::by_char_range('0'..='9'),
+///     Charset::by_char_range('0'..='9'),
+/// ]);
+///
+/// assert_eq!(brute_forcer.len(), 26 * 10 * 10);
+/// assert!(brute_forcer.clone().any(|s| s == "P42"));
+/// assert_eq!(brute_forcer.last(), Some("Z99".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaskBruteForce<'a> {
+    /// The charset of every position
+    charsets: Vec<Charset<'a>>,
+
+    /// This is the current string
+    pub current: String,
+
+    /// Reversed representation of current where each element is an index of the charset at its position
+    raw_current: Vec<usize>,
+
+    /// The remaining part of the keyspace
+    bounds: Bounds,
+}
+
+impl<'a> MaskBruteForce<'a> {
+    /// Returns a brute forcer which tries every combination of the charsets
+    ///
+    /// # Arguments
+    ///
+    /// * `charsets` - The charset of every position, starting with the first position
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace does not fit in a `u128`.
+    pub fn new(charsets: Vec<Charset<'a>>) -> MaskBruteForce<'a> {
+        Self::try_new(charsets).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which tries every combination of the charsets, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    pub fn try_new(charsets: Vec<Charset<'a>>) -> Result<MaskBruteForce<'a>, Error> {
+        let end = charsets
+            .iter()
+            .try_fold(1u128, |size, charset| {
+                size.checked_mul(charset.len() as u128)
+            })
+            .ok_or(Error::KeyspaceTooLarge)?;
+        Ok(MaskBruteForce {
+            raw_current: vec![0; charsets.len()],
+            charsets,
+            current: String::default(),
+            bounds: Bounds { next: 0, end },
+        })
+    }
+
+    /// Returns the charset of every position
+    pub fn charsets(&self) -> &[Charset<'a>] {
+        &self.charsets
+    }
+
+    /// Returns the number of strings left
+    pub fn remaining(&self) -> u128 {
+        self.bounds.end - self.bounds.next
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::mask::MaskBruteForce;
+    /// let mut brute_forcer = MaskBruteForce::new(vec![Charset::from("ab"), Charset::from("1")]);
+    ///
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("a1"));
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("b1"));
+    /// assert_eq!(brute_forcer.try_raw_next(), None);
+    /// ```
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        if self.bounds.next == self.bounds.end {
+            return None;
+        }
+        self.bounds.next += 1;
+
+        let cur = &mut self.current;
+        let charsets = &self.charsets;
+
+        cur.clear();
+        cur.extend(
+            self.raw_current
+                .iter()
+                .rev()
+                .zip(charsets.iter())
+                .map(|(&i, chars)| {
+                    assert!(i < chars.len(), "Bug: Invalid character index");
+                    chars[i]
+                }),
+        );
+
+        // "Add" 1 to self.raw_current, the last position is the first element
+        let last = charsets.len().wrapping_sub(1);
+        increment(&mut self.raw_current, |position| {
+            charsets[last - position].len()
+        });
+
+        Some(&self.current)
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+}
+
+impl<'a> Iterator for MaskBruteForce<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`MaskBruteForce::remaining`] in this case.
+///
+/// [`MaskBruteForce::remaining`]: struct.MaskBruteForce.html#method.remaining
+impl ExactSizeIterator for MaskBruteForce<'_> {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
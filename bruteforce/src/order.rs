@@ -0,0 +1,132 @@
+//! The orders in which a brute forcer can generate its strings
+//!
+//! All orders generate the same strings, so a brute forcer has the same length,
+//! shards and global indices in every order. Only the string at an index differs.
+
+use std::prelude::v1::*; // needed for std-compat
+
+/// The order in which a brute forcer generates its strings
+///
+/// The examples use the charset `AB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Order {
+    /// Shorter strings first and the last char changes fastest:
+    /// `A`, `B`, `AA`, `AB`, `BA`, `BB`
+    ///
+    /// The strings of the same length are sorted by the order of the charset.
+    /// This is the default order.
+    #[default]
+    Lexicographic,
+
+    /// Shorter strings first and the first char changes fastest:
+    /// `A`, `B`, `AA`, `BA`, `AB`, `BB`
+    Colexicographic,
+
+    /// Every string is followed by the longer strings it is a prefix of:
+    /// `A`, `AA`, `AB`, `B`, `BA`, `BB`
+    ///
+    /// This is the order of a sorted wordlist, if the charset is sorted.
+    /// It requires a maximum length, so only brute forcers created by
+    /// [`BruteForce::new_bounded`] support it.
+    ///
+    /// [`BruteForce::new_bounded`]: ../struct.BruteForce.html#method.new_bounded
+    DepthFirst,
+}
+
+/// Returns the number of strings of the length range which start with a prefix of length `depth`
+///
+/// The prefix itself is counted as well, if it is within the length range.
+fn subtree_size(base: usize, depth: usize, (min, max): (usize, usize)) -> u128 {
+    let base = base as u128;
+    let mut size = 0;
+    let mut power = 1;
+    for len in depth..=max {
+        if len >= min {
+            size += power;
+        }
+        if len < max {
+            power *= base;
+        }
+    }
+    size
+}
+
+/// Returns the position of a string in depth-first order
+///
+/// `raw` is the reversed representation of the string like `raw_current`.
+/// Returns `None` if the length of the string is not within the length range.
+pub(crate) fn depth_first_rank(
+    base: usize,
+    raw: &[usize],
+    lengths: (usize, usize),
+) -> Option<u128> {
+    let (min, max) = lengths;
+    if raw.len() < min || raw.len() > max {
+        return None;
+    }
+
+    // Every prefix within the length range comes before the string,
+    // every string with a smaller char at any position as well
+    let prefixes = (raw.len() - min) as u128;
+    raw.iter()
+        .rev()
+        .enumerate()
+        .try_fold(prefixes, |rank, (i, &digit)| {
+            let smaller = (digit as u128).checked_mul(subtree_size(base, i + 1, lengths))?;
+            rank.checked_add(smaller)
+        })
+}
+
+/// Returns the string at a position in depth-first order as reversed representation
+///
+/// # Panics
+///
+/// Panics if the position is not less than the number of strings.
+pub(crate) fn depth_first_unrank(
+    base: usize,
+    mut rank: u128,
+    lengths: (usize, usize),
+) -> Vec<usize> {
+    let (min, max) = lengths;
+    let mut digits = Vec::new();
+    loop {
+        if digits.len() >= min {
+            if rank == 0 {
+                break;
+            }
+            rank -= 1;
+        }
+        assert!(
+            digits.len() < max,
+            "The position must be within the keyspace"
+        );
+        let size = subtree_size(base, digits.len() + 1, lengths);
+        digits.push((rank / size) as usize);
+        rank %= size;
+    }
+    digits.reverse();
+    digits
+}
+
+/// Moves the reversed representation to the next string in depth-first order
+pub(crate) fn depth_first_step(raw: &mut Vec<usize>, base: usize, (min, max): (usize, usize)) {
+    if raw.len() < max {
+        raw.insert(0, 0);
+        return;
+    }
+
+    // Go back to the longest prefix which has a next sibling
+    while let Some(&last) = raw.first() {
+        if last == base - 1 {
+            raw.remove(0);
+        } else {
+            raw[0] += 1;
+            break;
+        }
+    }
+    // Strings shorter than the minimum length are only passed through
+    while raw.len() < min {
+        raw.insert(0, 0);
+    }
+}
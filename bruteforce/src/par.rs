@@ -0,0 +1,110 @@
+use std::prelude::v1::*; // needed for std-compat
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::BoundedBruteForce;
+
+/// The parallel iterator of a bounded brute forcer
+///
+/// The keyspace is split by index arithmetic, so the worker threads
+/// do not share any state while brute forcing.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// use rayon::prelude::*;
+///
+/// let brute_forcer = BruteForce::new_bounded(Charset::from("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), 1..=4);
+/// let password = brute_forcer
+///     .into_par_iter()
+///     .find_any(|s| s == "PASS");
+///
+/// assert_eq!(password, Some("PASS".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParBruteForce<'a> {
+    brute_forcer: BoundedBruteForce<'a>,
+}
+
+/// Only bounded brute forcers can be turned into a parallel iterator
+///
+/// # Panics
+///
+/// `into_par_iter` panics if the remaining keyspace does not fit in a `usize`.
+impl<'a> IntoParallelIterator for BoundedBruteForce<'a> {
+    type Iter = ParBruteForce<'a>;
+    type Item = String;
+
+    fn into_par_iter(self) -> Self::Iter {
+        // Fail early instead of inside of a worker thread
+        let _ = self.len();
+        ParBruteForce { brute_forcer: self }
+    }
+}
+
+impl ParallelIterator for ParBruteForce<'_> {
+    type Item = String;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.brute_forcer.len())
+    }
+}
+
+impl IndexedParallelIterator for ParBruteForce<'_> {
+    fn len(&self) -> usize {
+        self.brute_forcer.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(BruteForceProducer {
+            brute_forcer: self.brute_forcer,
+        })
+    }
+}
+
+/// Splits a bounded brute forcer into smaller brute forcers for rayon
+struct BruteForceProducer<'a> {
+    brute_forcer: BoundedBruteForce<'a>,
+}
+
+impl<'a> Producer for BruteForceProducer<'a> {
+    type Item = String;
+    type IntoIter = BoundedBruteForce<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.brute_forcer
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let bounds = self.brute_forcer.bounds();
+        let mid = bounds.next + index as u128;
+        let left = self.brute_forcer.with_bounds(bounds.next..mid);
+        let right = self.brute_forcer.with_bounds(mid..bounds.end);
+        (
+            BruteForceProducer { brute_forcer: left },
+            BruteForceProducer {
+                brute_forcer: right,
+            },
+        )
+    }
+}
@@ -0,0 +1,573 @@
+//! Brute forcing strings in which every char of the charset appears at most once
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::convert::TryFrom;
+use std::ops::{Range, RangeInclusive};
+
+use crate::charset::Charset;
+use crate::combinations::combinations;
+use crate::error::Error;
+use crate::Bounds;
+
+/// Returns the number of permutations of `k` out of `n` elements, or `None` if it does not fit in a `u128`
+fn permutations(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1u128, |count, i| count.checked_mul(i as u128))
+}
+
+/// Returns the number of permutations which are shorter than `len`
+///
+/// This is the global index of the first permutation of length `len`,
+/// or `None` if it does not fit in a `u128`.
+fn permutation_offset_of_length(n: usize, len: usize) -> Option<u128> {
+    // There are no permutations longer than the charset
+    (0..len.min(n + 1)).try_fold(0u128, |offset, k| offset.checked_add(permutations(n, k)?))
+}
+
+/// Represents a brute-forcing instance which tries every permutation of the charset
+/// within a length range
+///
+/// No char is repeated within a string, so the keyspace is much smaller than the one of
+/// [`BruteForce`]. The strings are generated shortest first and the strings of the same
+/// length are sorted by the order of the charset.
+///
+/// Like [`BruteForce`], every permutation has a global index: the number of shorter
+/// permutations plus its rank among the permutations of its length. The rank is
+/// computed from the Lehmer code of the permutation, so the keyspace can be split
+/// into shards without generating it.
+///
+/// [`BruteForce`]: ../struct.BruteForce.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::charset::Charset;
+/// use bruteforce::permutations::PermutationBruteForce;
+/// let brute_forcer = PermutationBruteForce::new(Charset::from("ABC"), 2..=3);
+///
+/// assert_eq!(brute_forcer.len(), 6 + 6);
+/// assert_eq!(
+///     brute_forcer.take(8).collect::<Vec<String>>(),
+///     ["AB", "AC", "BA", "BC", "CA", "CB", "ABC", "ACB"]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PermutationBruteForce<'a> {
+    /// The charset of the permutations
+    chars: Charset<'a>,
+
+    /// This is the current string
+    pub current: String,
+
+    /// Representation of current where each element is an index of the charset
+    raw_current: Vec<usize>,
+
+    /// Whether an index of the charset is used by `raw_current`
+    used: Vec<bool>,
+
+    /// The remaining part of the keyspace
+    bounds: Bounds,
+}
+
+impl<'a> PermutationBruteForce<'a> {
+    /// Returns a brute forcer which tries every permutation within a length range
+    ///
+    /// # Arguments
+    ///
+    /// * `charset` - A char array that contains all chars to be tried
+    /// * `lengths` - The range of the lengths of the permutations, which is cut off at the length of the charset
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or if the keyspace does not fit in a `u128`.
+    pub fn new(charset: Charset<'a>, lengths: RangeInclusive<usize>) -> PermutationBruteForce<'a> {
+        Self::try_new(charset, lengths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which tries every permutation within a length range, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyLengthRange`] if the range is empty
+    /// and [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::EmptyLengthRange`]: ../error/enum.Error.html#variant.EmptyLengthRange
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    pub fn try_new(
+        charset: Charset<'a>,
+        lengths: RangeInclusive<usize>,
+    ) -> Result<PermutationBruteForce<'a>, Error> {
+        let (min, max) = (*lengths.start(), *lengths.end());
+        if min > max {
+            return Err(Error::EmptyLengthRange);
+        }
+        let next = permutation_offset_of_length(charset.len(), min);
+        let end = permutation_offset_of_length(charset.len(), max.saturating_add(1));
+        let (next, end) = next.zip(end).ok_or(Error::KeyspaceTooLarge)?;
+
+        let mut brute_forcer = PermutationBruteForce {
+            used: vec![false; charset.len()],
+            chars: charset,
+            current: String::default(),
+            raw_current: Vec::new(),
+            bounds: Bounds { next, end },
+        };
+        brute_forcer.seek(next);
+        Ok(brute_forcer)
+    }
+
+    /// Returns the charset of the permutations
+    pub fn chars(&self) -> &Charset<'a> {
+        &self.chars
+    }
+
+    /// Moves the brute forcer to a global index within its bounds
+    fn seek(&mut self, index: u128) {
+        self.bounds.next = index;
+        if index == self.bounds.end {
+            // There is no permutation at the end, so the position does not matter
+            return;
+        }
+        self.raw_current = self.unrank(index);
+        self.used.iter_mut().for_each(|used| *used = false);
+        for &i in &self.raw_current {
+            self.used[i] = true;
+        }
+    }
+
+    /// Converts a global index into the indices of the charset using the Lehmer code
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no permutation at the index.
+    fn unrank(&self, index: u128) -> Vec<usize> {
+        let n = self.chars.len();
+        let len = (0..=n)
+            .take_while(|&len| matches!(permutation_offset_of_length(n, len + 1), Some(end) if end <= index))
+            .count();
+        assert!(
+            len <= n,
+            "The index must be less than the number of permutations"
+        );
+        let mut rank = index
+            - permutation_offset_of_length(n, len).expect("Bug: The offset is less than the index");
+
+        // Every digit of the Lehmer code selects one of the unused chars
+        let mut unused = (0..n).collect::<Vec<usize>>();
+        (0..len)
+            .map(|position| {
+                let size = permutations(n - position - 1, len - position - 1)
+                    .expect("Bug: The size is less than the index");
+                let digit = (rank / size) as usize;
+                rank %= size;
+                unused.remove(digit)
+            })
+            .collect()
+    }
+
+    /// Returns the permutation at a global index
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than the number of permutations of the charset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::permutations::PermutationBruteForce;
+    /// let brute_forcer = PermutationBruteForce::new(Charset::from("ABC"), 1..=3);
+    ///
+    /// assert_eq!(brute_forcer.candidate_at(0), "");
+    /// assert_eq!(brute_forcer.candidate_at(4), "AB");
+    /// assert_eq!(brute_forcer.candidate_at(15), "CBA");
+    /// ```
+    pub fn candidate_at(&self, index: u128) -> String {
+        self.unrank(index)
+            .into_iter()
+            .map(|i| self.chars[i])
+            .collect()
+    }
+
+    /// Returns the global index of a permutation using its Lehmer code
+    ///
+    /// Returns `None` if the string contains a char which is not in the charset or which is repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::permutations::PermutationBruteForce;
+    /// let brute_forcer = PermutationBruteForce::new(Charset::from("ABC"), 1..=3);
+    ///
+    /// assert_eq!(brute_forcer.index_of("CBA"), Some(15));
+    /// assert_eq!(brute_forcer.index_of("ABA"), None);
+    /// ```
+    pub fn index_of(&self, candidate: &str) -> Option<u128> {
+        let n = self.chars.len();
+        let len = candidate.chars().count();
+        let mut used = vec![false; n];
+        let mut rank: u128 = 0;
+        for (position, c1) in candidate.chars().enumerate() {
+            let i = self.chars.iter().position(|&c2| c1 == c2)?;
+            if used[i] {
+                return None;
+            }
+            used[i] = true;
+            // The digit of the Lehmer code is the number of smaller unused chars
+            let digit = used[..i].iter().filter(|&&used| !used).count() as u128;
+            let size = permutations(n - position - 1, len - position - 1)?;
+            rank = rank.checked_add(digit.checked_mul(size)?)?;
+        }
+        permutation_offset_of_length(n, len)?.checked_add(rank)
+    }
+
+    /// Returns the global index of the next permutation
+    pub fn index(&self) -> u128 {
+        self.bounds.next
+    }
+
+    /// Returns the number of permutations left
+    pub fn remaining(&self) -> u128 {
+        self.bounds.end - self.bounds.next
+    }
+
+    /// Returns the global index after the last permutation
+    pub fn end(&self) -> u128 {
+        self.bounds.end
+    }
+
+    /// Returns one of `count` contiguous, disjoint shards of the remaining keyspace
+    ///
+    /// See [`BoundedBruteForce::shard`] for details.
+    ///
+    /// [`BoundedBruteForce::shard`]: ../struct.BoundedBruteForce.html#method.shard
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `count`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::permutations::PermutationBruteForce;
+    /// let brute_forcer = PermutationBruteForce::new(Charset::from("ABCD"), 4..=4);
+    /// let shard = brute_forcer.shard(3, 4);
+    ///
+    /// assert_eq!(shard.len(), 6);
+    /// assert_eq!(shard.last(), Some("DCBA".to_string()));
+    /// ```
+    pub fn shard(&self, index: usize, count: usize) -> PermutationBruteForce<'a> {
+        let Range { start, end } = self.bounds.shard(index, count);
+
+        let mut shard = self.clone();
+        shard.bounds.end = end;
+        shard.seek(start);
+        shard
+    }
+
+    /// Splits the remaining keyspace into `count` contiguous, disjoint shards
+    ///
+    /// See [`shard`] for details.
+    ///
+    /// [`shard`]: #method.shard
+    pub fn shards(&self, count: usize) -> Vec<PermutationBruteForce<'a>> {
+        (0..count).map(|index| self.shard(index, count)).collect()
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::permutations::PermutationBruteForce;
+    /// let mut brute_forcer = PermutationBruteForce::new(Charset::from("AB"), 2..=2);
+    ///
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("AB"));
+    /// assert_eq!(brute_forcer.try_raw_next(), Some("BA"));
+    /// assert_eq!(brute_forcer.try_raw_next(), None);
+    /// ```
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        if self.bounds.next == self.bounds.end {
+            return None;
+        }
+        self.bounds.next += 1;
+
+        let chars = &self.chars;
+        self.current.clear();
+        self.current
+            .extend(self.raw_current.iter().map(|&i| chars[i]));
+
+        self.step();
+        Some(&self.current)
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+
+    /// Moves `raw_current` to the next permutation
+    fn step(&mut self) {
+        let raw = &mut self.raw_current;
+        let used = &mut self.used;
+        let (n, mut len) = (used.len(), raw.len());
+
+        // Replace the last position which has a greater unused char
+        // and fill the positions after it with the smallest unused chars
+        while let Some(last) = raw.pop() {
+            used[last] = false;
+            if let Some(i) = (last + 1..n).find(|&i| !used[i]) {
+                used[i] = true;
+                raw.push(i);
+                break;
+            }
+        }
+        if raw.is_empty() {
+            // Every permutation of this length was tried, so the next one is longer
+            len += 1;
+        }
+        if len > n {
+            return;
+        }
+        for (i, used) in used.iter_mut().enumerate() {
+            if raw.len() == len {
+                break;
+            }
+            if !*used {
+                *used = true;
+                raw.push(i);
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for PermutationBruteForce<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<String> {
+        // Skipping is done by index arithmetic instead of generating every permutation
+        let target = self.bounds.next.saturating_add(n as u128);
+        self.seek(target.min(self.bounds.end));
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`PermutationBruteForce::remaining`] in this case.
+///
+/// [`PermutationBruteForce::remaining`]: struct.PermutationBruteForce.html#method.remaining
+impl ExactSizeIterator for PermutationBruteForce<'_> {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
+
+impl DoubleEndedIterator for PermutationBruteForce<'_> {
+    fn next_back(&mut self) -> Option<String> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<String> {
+        let bounds = &mut self.bounds;
+        if bounds.end - bounds.next <= n as u128 {
+            bounds.end = bounds.next;
+            return None;
+        }
+        bounds.end -= n as u128 + 1;
+        let index = bounds.end;
+        Some(self.candidate_at(index))
+    }
+}
+
+/// Represents a brute-forcing instance which tries every distinct permutation of a multiset of chars
+///
+/// This tries every anagram of a string exactly once, even if chars are repeated.
+/// The permutations are sorted by the order in which the chars first appear in the multiset,
+/// so the first permutation is the multiset with repeated chars grouped together.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::charset::Charset;
+/// use bruteforce::permutations::MultisetPermutationBruteForce;
+/// let brute_forcer = MultisetPermutationBruteForce::new(Charset::from("PASS"));
+///
+/// // 4! / 2! instead of 4! strings
+/// assert_eq!(brute_forcer.len(), 12);
+/// assert_eq!(
+///     brute_forcer.take(4).collect::<Vec<String>>(),
+///     ["PASS", "PSAS", "PSSA", "APSS"]
+/// );
+///
+/// let brute_forcer = MultisetPermutationBruteForce::new(Charset::from("PASSWORD"));
+/// assert_eq!(brute_forcer.len(), 20160);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultisetPermutationBruteForce {
+    /// The distinct chars of the multiset in the order of their first appearance
+    distinct: Vec<char>,
+
+    /// This is the current string
+    pub current: String,
+
+    /// Representation of current where each element is an index of `distinct`
+    raw_current: Vec<usize>,
+
+    /// The remaining part of the keyspace
+    bounds: Bounds,
+}
+
+impl MultisetPermutationBruteForce {
+    /// Returns a brute forcer which tries every distinct permutation of a multiset
+    ///
+    /// # Arguments
+    ///
+    /// * `multiset` - The chars to be permuted, which may contain repeated chars
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace does not fit in a `u128`.
+    pub fn new(multiset: Charset) -> MultisetPermutationBruteForce {
+        Self::try_new(multiset).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which tries every distinct permutation of a multiset, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::KeyspaceTooLarge`] if the keyspace does not fit in a `u128`.
+    ///
+    /// [`Error::KeyspaceTooLarge`]: ../error/enum.Error.html#variant.KeyspaceTooLarge
+    pub fn try_new(multiset: Charset) -> Result<MultisetPermutationBruteForce, Error> {
+        let mut distinct = Vec::new();
+        let mut counts = Vec::new();
+        for &c in multiset.iter() {
+            match distinct.iter().position(|&d| d == c) {
+                Some(i) => counts[i] += 1,
+                None => {
+                    distinct.push(c);
+                    counts.push(1);
+                }
+            }
+        }
+
+        // The multinomial coefficient is the product of the number of ways
+        // to place every group of equal chars into the remaining positions
+        let mut placed = 0;
+        let end = counts
+            .iter()
+            .try_fold(1u128, |count, &group| {
+                placed += group;
+                count.checked_mul(combinations(placed, group)?)
+            })
+            .ok_or(Error::KeyspaceTooLarge)?;
+
+        let raw_current = counts
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &count)| std::iter::repeat_n(i, count))
+            .collect();
+        Ok(MultisetPermutationBruteForce {
+            distinct,
+            current: String::default(),
+            raw_current,
+            bounds: Bounds { next: 0, end },
+        })
+    }
+
+    /// Returns the number of permutations left
+    pub fn remaining(&self) -> u128 {
+        self.bounds.end - self.bounds.next
+    }
+
+    /// This returns the next element, or `None` if the keyspace is exhausted
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        if self.bounds.next == self.bounds.end {
+            return None;
+        }
+        self.bounds.next += 1;
+
+        let distinct = &self.distinct;
+        self.current.clear();
+        self.current
+            .extend(self.raw_current.iter().map(|&i| distinct[i]));
+
+        // The next permutation swaps the last ascent with the smallest greater element
+        // after it and sorts the elements after the ascent, which skips repeated strings
+        let raw = &mut self.raw_current;
+        if let Some(ascent) = (1..raw.len()).rev().find(|&i| raw[i - 1] < raw[i]) {
+            let pivot = ascent - 1;
+            let successor = (ascent..raw.len())
+                .rev()
+                .find(|&i| raw[i] > raw[pivot])
+                .expect("Bug: The ascent has a greater element");
+            raw.swap(pivot, successor);
+            raw[ascent..].reverse();
+        }
+
+        Some(&self.current)
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+}
+
+impl Iterator for MultisetPermutationBruteForce {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`MultisetPermutationBruteForce::remaining`] in this case.
+///
+/// [`MultisetPermutationBruteForce::remaining`]: struct.MultisetPermutationBruteForce.html#method.remaining
+impl ExactSizeIterator for MultisetPermutationBruteForce {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
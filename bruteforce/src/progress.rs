@@ -0,0 +1,271 @@
+//! Reporting the progress of long-running brute forcers to other threads
+//!
+//! A [`ProgressBruteForce`] counts the tried strings locally and adds them to a shared
+//! [`Progress`] only every [`SAMPLE_INTERVAL`] strings, so `raw_next` stays fast.
+//! Any thread can take a [`ProgressReport`] of the shared progress at any time.
+//!
+//! [`ProgressBruteForce`]: struct.ProgressBruteForce.html
+//! [`Progress`]: struct.Progress.html
+//! [`SAMPLE_INTERVAL`]: constant.SAMPLE_INTERVAL.html
+//! [`ProgressReport`]: struct.ProgressReport.html
+//!
+//! # Example
+//!
+//! ```rust
+//! use bruteforce::BruteForce;
+//! use bruteforce::charset::Charset;
+//! use bruteforce::progress::Progress;
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! let brute_forcer = BruteForce::new_bounded(Charset::from("ABCDEF"), 1..=6);
+//! let progress = Arc::new(Progress::new(&brute_forcer));
+//!
+//! // Every worker reports to the same progress
+//! let workers = brute_forcer
+//!     .shards(4)
+//!     .into_iter()
+//!     .map(|shard| {
+//!         let mut shard = shard.with_progress(progress.clone());
+//!         thread::spawn(move || while shard.try_raw_next().is_some() {})
+//!     })
+//!     .collect::<Vec<_>>();
+//!
+//! let report = progress.report();
+//! println!("{:.1}% done, {:.0} strings/s, ETA {:?}", report.percent().unwrap(), report.rate(), report.eta());
+//!
+//! workers.into_iter().for_each(|worker| worker.join().unwrap());
+//! assert_eq!(progress.report().percent(), Some(100.0));
+//!
+//! // The shards are tried at the same time, so there is no single index of the next string
+//! assert_eq!(progress.report().index, None);
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{BoundedBruteForce, BruteForce};
+
+/// The number of strings a [`ProgressBruteForce`] tries before it updates the shared progress
+///
+/// [`ProgressBruteForce`]: struct.ProgressBruteForce.html
+pub const SAMPLE_INTERVAL: u64 = 1024;
+
+/// The progress of one or more brute forcers, which can be shared between threads
+#[derive(Debug)]
+pub struct Progress {
+    tried: AtomicU64,
+    start_index: Option<u128>,
+    /// The number of brute forcers reporting to the progress
+    reporters: AtomicUsize,
+    /// Whether the strings are tried in order from `start_index` by a single brute forcer
+    in_order: AtomicBool,
+    total: Option<u128>,
+    start: Instant,
+}
+
+impl Progress {
+    /// Returns the progress of the remaining keyspace of a brute forcer
+    ///
+    /// The elapsed time is measured from now on.
+    pub fn new(brute_forcer: &BruteForce) -> Progress {
+        Progress {
+            tried: AtomicU64::new(0),
+            start_index: brute_forcer.index(),
+            reporters: AtomicUsize::new(0),
+            in_order: AtomicBool::new(true),
+            total: brute_forcer.remaining(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Adds a number of tried strings
+    pub fn add(&self, tried: u64) {
+        self.tried.fetch_add(tried, Ordering::Relaxed);
+    }
+
+    /// Registers a brute forcer reporting to the progress
+    ///
+    /// The index of the next string is only known if a single brute forcer
+    /// starts at the index of the progress.
+    fn attach(&self, brute_forcer: &BruteForce) {
+        let first = self.reporters.fetch_add(1, Ordering::Relaxed) == 0;
+        if !first || brute_forcer.index() != self.start_index {
+            self.in_order.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns a snapshot of the progress
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// use bruteforce::progress::Progress;
+    /// use std::sync::Arc;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=12);
+    /// let progress = Arc::new(Progress::new(&brute_forcer));
+    ///
+    /// let mut brute_forcer = brute_forcer.with_progress(progress.clone());
+    /// brute_forcer.nth(2047);
+    /// let report = progress.report();
+    /// assert_eq!(report.tried, 2048);
+    /// assert_eq!(report.index, Some(1 + 2048));
+    /// ```
+    pub fn report(&self) -> ProgressReport {
+        let tried = self.tried.load(Ordering::Relaxed);
+        ProgressReport {
+            tried,
+            index: self
+                .start_index
+                .filter(|_| self.in_order.load(Ordering::Relaxed))
+                .and_then(|index| index.checked_add(u128::from(tried))),
+            total: self.total,
+            elapsed: self.start.elapsed(),
+        }
+    }
+}
+
+/// A snapshot of a [`Progress`]
+///
+/// [`Progress`]: struct.Progress.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressReport {
+    /// The number of tried strings
+    pub tried: u64,
+
+    /// The global index of the next string, if the strings are tried in order by one brute forcer
+    ///
+    /// It is `None` as soon as several brute forcers, e.g. shards, report to the same progress,
+    /// or if the brute forcer does not start at the index of the progress.
+    pub index: Option<u128>,
+
+    /// The number of strings to be tried, if the brute forcer is bounded
+    pub total: Option<u128>,
+
+    /// The time since the progress was created
+    pub elapsed: Duration,
+}
+
+impl ProgressReport {
+    /// Returns the percentage of tried strings, if the brute forcer is bounded
+    pub fn percent(&self) -> Option<f64> {
+        self.total.map(|total| match total {
+            0 => 100.0,
+            total => self.tried as f64 / total as f64 * 100.0,
+        })
+    }
+
+    /// Returns the number of tried strings per second
+    pub fn rate(&self) -> f64 {
+        self.tried as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// Returns the estimated time until all strings are tried
+    ///
+    /// Returns `None` if the brute forcer is unbounded or if no strings were tried yet.
+    pub fn eta(&self) -> Option<Duration> {
+        let left = self.total?.saturating_sub(u128::from(self.tried));
+        Duration::try_from_secs_f64(left as f64 / self.rate()).ok()
+    }
+}
+
+/// A brute forcer which reports its progress
+///
+/// The pending strings are added to the progress when the brute forcer is exhausted or dropped.
+#[derive(Debug)]
+pub struct ProgressBruteForce<'a> {
+    brute_forcer: BruteForce<'a>,
+    progress: Arc<Progress>,
+    pending: u64,
+}
+
+impl<'a> BruteForce<'a> {
+    /// Returns the brute forcer reporting to a progress
+    ///
+    /// See the [module documentation] for an example.
+    ///
+    /// [module documentation]: progress/index.html
+    pub fn with_progress(self, progress: Arc<Progress>) -> ProgressBruteForce<'a> {
+        progress.attach(&self);
+        ProgressBruteForce {
+            brute_forcer: self,
+            progress,
+            pending: 0,
+        }
+    }
+}
+
+impl<'a> BoundedBruteForce<'a> {
+    /// Returns the brute forcer reporting to a progress
+    ///
+    /// See the [module documentation] for an example.
+    ///
+    /// [module documentation]: progress/index.html
+    pub fn with_progress(self, progress: Arc<Progress>) -> ProgressBruteForce<'a> {
+        self.brute_forcer.with_progress(progress)
+    }
+}
+
+impl<'a> ProgressBruteForce<'a> {
+    /// Returns the brute forcer
+    pub fn brute_forcer(&self) -> &BruteForce<'a> {
+        &self.brute_forcer
+    }
+
+    /// Returns the progress
+    pub fn progress(&self) -> &Arc<Progress> {
+        &self.progress
+    }
+
+    /// Adds the pending strings to the progress
+    fn flush(&mut self) {
+        if self.pending > 0 {
+            self.progress.add(self.pending);
+            self.pending = 0;
+        }
+    }
+
+    /// This returns the next element, or `None` if the keyspace of a bounded brute forcer is exhausted
+    pub fn try_raw_next(&mut self) -> Option<&str> {
+        if self.brute_forcer.remaining() == Some(0) {
+            self.flush();
+            return None;
+        }
+        self.pending += 1;
+        if self.pending == SAMPLE_INTERVAL {
+            self.flush();
+        }
+        self.brute_forcer.try_raw_next()
+    }
+
+    /// This returns the next element without unnecessary boxing in a Option
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyspace of a bounded brute forcer is exhausted.
+    pub fn raw_next(&mut self) -> &str {
+        self.try_raw_next()
+            .expect("The keyspace of the brute forcer is exhausted")
+    }
+}
+
+impl Iterator for ProgressBruteForce<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.try_raw_next().map(str::to_string)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.brute_forcer.size_hint()
+    }
+}
+
+impl Drop for ProgressBruteForce<'_> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
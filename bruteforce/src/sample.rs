@@ -0,0 +1,113 @@
+//! Drawing random strings from a keyspace
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::iter::Take;
+
+use crate::shuffle::{splitmix64, ShuffledBruteForce};
+use crate::{BoundedBruteForce, BruteForce};
+
+/// The increment of the SplitMix64 generator
+const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// An endless iterator over uniformly random strings of a bounded brute forcer, with replacement
+///
+/// The strings are drawn by a seeded SplitMix64 generator, so the same seed always
+/// gives the same strings. It does not depend on `std` or an external random number generator.
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let brute_forcer = BruteForce::new_bounded(Charset::from("ABCDEF"), 4..=8);
+///
+/// let samples = brute_forcer.sample(42).take(100).collect::<Vec<String>>();
+/// assert!(samples.iter().all(|s| (4..=8).contains(&s.len())));
+/// assert!(brute_forcer.sample(42).take(100).eq(samples));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sample<'a> {
+    brute_forcer: BruteForce<'a>,
+    start: u128,
+    size: u128,
+    state: u64,
+}
+
+impl Sample<'_> {
+    fn next_u64(&mut self) -> u64 {
+        let value = splitmix64(self.state);
+        self.state = self.state.wrapping_add(GAMMA);
+        value
+    }
+
+    /// Returns a uniformly random number below `self.size`
+    fn next_below_size(&mut self) -> u128 {
+        // Numbers from the incomplete last multiple of `size` are rejected to avoid a bias
+        let limit = u128::MAX - (u128::MAX - self.size + 1) % self.size;
+        loop {
+            let value = u128::from(self.next_u64()) << 64 | u128::from(self.next_u64());
+            if value <= limit {
+                return value % self.size;
+            }
+        }
+    }
+}
+
+impl<'a> BoundedBruteForce<'a> {
+    /// Returns an endless iterator over uniformly random remaining strings, with replacement
+    ///
+    /// Every string is equally likely regardless of its length.
+    pub fn sample(&self, seed: u64) -> Sample<'a> {
+        let bounds = self.bounds();
+        Sample {
+            brute_forcer: self.brute_forcer.clone(),
+            start: bounds.next,
+            size: bounds.end - bounds.next,
+            state: seed,
+        }
+    }
+
+    /// Returns `count` random remaining strings without replacement
+    ///
+    /// These are the first strings of [`shuffled`], so they are drawn by a keyed
+    /// pseudo-random permutation. If `count` is greater than the number of remaining strings,
+    /// every remaining string is returned.
+    ///
+    /// [`shuffled`]: #method.shuffled
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=3);
+    ///
+    /// let mut samples = brute_forcer.sample_distinct(10, 42).collect::<Vec<String>>();
+    /// samples.sort();
+    /// samples.dedup();
+    /// assert_eq!(samples.len(), 10);
+    /// ```
+    pub fn sample_distinct(&self, count: usize, seed: u64) -> Take<ShuffledBruteForce<'a>> {
+        self.shuffled(seed).take(count)
+    }
+}
+
+impl Iterator for Sample<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.size == 0 {
+            return None;
+        }
+        let index = self.start + self.next_below_size();
+        Some(self.brute_forcer.candidate_at(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.size {
+            0 => (0, Some(0)),
+            _ => (usize::MAX, None),
+        }
+    }
+}
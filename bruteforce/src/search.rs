@@ -0,0 +1,248 @@
+//! Searching a keyspace for strings matching a predicate
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{BoundedBruteForce, BruteForce};
+
+/// The matches and statistics of a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    /// The strings matching the predicate
+    pub matches: Vec<String>,
+
+    /// The number of strings the predicate was evaluated on
+    pub tried: u128,
+
+    /// The time the search took
+    pub elapsed: Duration,
+}
+
+impl SearchResult {
+    /// Returns the first match, if any
+    pub fn first(&self) -> Option<&str> {
+        self.matches.first().map(String::as_str)
+    }
+
+    /// Returns the number of tried strings per second
+    pub fn rate(&self) -> f64 {
+        self.tried as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+/// Evaluates the predicate on the strings of a brute forcer until it is exhausted or cancelled
+///
+/// Returns the matches and the number of tried strings.
+fn search<P>(
+    brute_forcer: &mut BruteForce,
+    mut predicate: P,
+    first_only: bool,
+    cancel: &AtomicBool,
+) -> (Vec<String>, u128)
+where
+    P: FnMut(&str) -> bool,
+{
+    let mut matches = Vec::new();
+    let mut tried = 0;
+    while !cancel.load(Ordering::Relaxed) {
+        let candidate = match brute_forcer.try_raw_next() {
+            Some(candidate) => candidate,
+            None => break,
+        };
+        tried += 1;
+        if predicate(candidate) {
+            matches.push(candidate.to_string());
+            if first_only {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+    (matches, tried)
+}
+
+impl BruteForce<'_> {
+    /// Searches for the first string matching the predicate
+    ///
+    /// The predicate is evaluated on the buffer of [`raw_next`], so no string is allocated
+    /// except for the match. The brute forcer continues after the match, so the search can be resumed.
+    /// An unbounded brute forcer only stops at a match.
+    ///
+    /// [`raw_next`]: #method.raw_next
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("ABCPS"), 1..=4);
+    ///
+    /// let result = brute_forcer.find(|s| s == "PASS");
+    /// assert_eq!(result.first(), Some("PASS"));
+    /// assert_eq!(result.tried, brute_forcer.index_of("PASS").unwrap());
+    /// ```
+    pub fn find<P>(&mut self, predicate: P) -> SearchResult
+    where
+        P: FnMut(&str) -> bool,
+    {
+        let start = Instant::now();
+        let (matches, tried) = search(self, predicate, true, &AtomicBool::new(false));
+        SearchResult {
+            matches,
+            tried,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+impl BoundedBruteForce<'_> {
+    /// Searches for the first string matching the predicate
+    ///
+    /// See [`BruteForce::find`] for details.
+    ///
+    /// [`BruteForce::find`]: ../struct.BruteForce.html#method.find
+    pub fn find<P>(&mut self, predicate: P) -> SearchResult
+    where
+        P: FnMut(&str) -> bool,
+    {
+        self.brute_forcer.find(predicate)
+    }
+
+    /// Searches for all strings matching the predicate
+    ///
+    /// The search ends when the keyspace is exhausted, so it is only available for bounded brute forcers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=3);
+    ///
+    /// let result = brute_forcer.find_all(|s| s.starts_with("BA"));
+    /// assert_eq!(result.matches, ["BA", "BAA", "BAB"]);
+    /// assert_eq!(result.tried, 2 + 4 + 8);
+    /// ```
+    ///
+    /// An unbounded brute forcer would never finish:
+    ///
+    /// ```compile_fail
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let mut brute_forcer = BruteForce::new(Charset::from("AB"));
+    ///
+    /// brute_forcer.find_all(|s| s.starts_with("BA"));
+    /// ```
+    pub fn find_all<P>(&mut self, predicate: P) -> SearchResult
+    where
+        P: FnMut(&str) -> bool,
+    {
+        let start = Instant::now();
+        let (matches, tried) = search(
+            &mut self.brute_forcer,
+            predicate,
+            false,
+            &AtomicBool::new(false),
+        );
+        SearchResult {
+            matches,
+            tried,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    /// Searches the remaining keyspace for a string matching the predicate on multiple threads
+    ///
+    /// The keyspace is split into one [`shard`] per thread. As soon as one thread finds a match,
+    /// all threads stop. If multiple threads find a match at the same time,
+    /// the match with the smallest index is returned.
+    ///
+    /// [`shard`]: #method.shard
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("ABCPS"), 1..=4);
+    ///
+    /// let result = brute_forcer.find_par(|s| s == "PASS", 4);
+    /// assert_eq!(result.first(), Some("PASS"));
+    /// ```
+    pub fn find_par<P>(&self, predicate: P, threads: usize) -> SearchResult
+    where
+        P: Fn(&str) -> bool + Sync,
+    {
+        let mut result = self.search_par(predicate, threads, true);
+        result.matches.truncate(1);
+        result
+    }
+
+    /// Searches the remaining keyspace for all strings matching the predicate on multiple threads
+    ///
+    /// The matches are sorted by their index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=3);
+    ///
+    /// let result = brute_forcer.find_all_par(|s| s.starts_with("BA"), 3);
+    /// assert_eq!(result.matches, ["BA", "BAA", "BAB"]);
+    /// assert_eq!(result.tried, 2 + 4 + 8);
+    /// ```
+    pub fn find_all_par<P>(&self, predicate: P, threads: usize) -> SearchResult
+    where
+        P: Fn(&str) -> bool + Sync,
+    {
+        self.search_par(predicate, threads, false)
+    }
+
+    fn search_par<P>(&self, predicate: P, threads: usize, first_only: bool) -> SearchResult
+    where
+        P: Fn(&str) -> bool + Sync,
+    {
+        assert!(threads > 0, "The number of threads must not be zero");
+        let start = Instant::now();
+        let cancel = AtomicBool::new(false);
+        let shards = self.shards(threads);
+
+        let results = thread::scope(|scope| {
+            let handles = shards
+                .into_iter()
+                .map(|mut shard| {
+                    let (predicate, cancel) = (&predicate, &cancel);
+                    scope.spawn(move || {
+                        search(&mut shard.brute_forcer, predicate, first_only, cancel)
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("A search thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        // The shards are in order, so the matches are sorted by their index
+        let mut result = SearchResult {
+            matches: Vec::new(),
+            tried: 0,
+            elapsed: start.elapsed(),
+        };
+        for (matches, tried) in results {
+            result.matches.extend(matches);
+            result.tried += tried;
+        }
+        result
+    }
+}
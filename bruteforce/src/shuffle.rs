@@ -0,0 +1,199 @@
+//! Trying the strings of a keyspace in a pseudo-random order
+
+use std::prelude::v1::*; // needed for std-compat
+
+use std::convert::TryFrom;
+
+use crate::{BoundedBruteForce, BruteForce};
+
+/// The number of rounds of the Feistel network
+const ROUNDS: usize = 8;
+
+/// A step of the SplitMix64 generator, which is used as round function and key schedule
+pub(crate) fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// A keyed permutation of the positions `0..size`
+///
+/// A balanced Feistel network permutes the smallest power of four which is at least `size`.
+/// Results outside of `0..size` are permuted again until they are inside ("cycle walking"),
+/// which takes less than four rounds on average.
+#[derive(Debug, Clone)]
+struct Permutation {
+    size: u128,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl Permutation {
+    fn new(size: u128, seed: u64) -> Permutation {
+        let bits = 128 - size.saturating_sub(1).leading_zeros();
+        let mut keys = [0; ROUNDS];
+        let mut state = seed;
+        for key in &mut keys {
+            state = splitmix64(state);
+            *key = state;
+        }
+        Permutation {
+            size,
+            half_bits: bits.div_ceil(2),
+            keys,
+        }
+    }
+
+    fn feistel(&self, position: u128) -> u128 {
+        let mask = match self.half_bits {
+            64 => u64::MAX,
+            bits => (1 << bits) - 1,
+        };
+        let mut left = (position >> self.half_bits) as u64;
+        let mut right = position as u64 & mask;
+        for &key in &self.keys {
+            let mixed = left ^ (splitmix64(right ^ key) & mask);
+            left = right;
+            right = mixed;
+        }
+        (u128::from(left) << self.half_bits) | u128::from(right)
+    }
+
+    fn apply(&self, mut position: u128) -> u128 {
+        loop {
+            position = self.feistel(position);
+            if position < self.size {
+                return position;
+            }
+        }
+    }
+}
+
+/// A bounded brute forcer which tries every remaining string exactly once in a pseudo-random order
+///
+/// The order is a keyed permutation of the global indices, so it is reproducible from
+/// the seed and a shuffled brute forcer can be resumed by its [`position`].
+///
+/// [`position`]: #method.position
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::BruteForce;
+/// use bruteforce::charset::Charset;
+/// let brute_forcer = BruteForce::new_bounded(Charset::from("0123456789"), 4..=4);
+///
+/// let mut shuffled = brute_forcer.shuffled(42).collect::<Vec<String>>();
+/// assert_eq!(shuffled.len(), 10_000);
+/// assert_ne!(shuffled[..100], brute_forcer.clone().take(100).collect::<Vec<String>>()[..]);
+///
+/// // The same seed gives the same order and every string is tried once
+/// assert_eq!(brute_forcer.shuffled(42).next().as_ref(), shuffled.first());
+/// shuffled.sort();
+/// assert!(shuffled.into_iter().eq(brute_forcer));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShuffledBruteForce<'a> {
+    brute_forcer: BruteForce<'a>,
+    start: u128,
+    permutation: Permutation,
+    position: u128,
+}
+
+impl ShuffledBruteForce<'_> {
+    /// Returns the number of strings which were already tried
+    pub fn position(&self) -> u128 {
+        self.position
+    }
+
+    /// Returns the shuffled brute forcer continuing after `position` strings
+    ///
+    /// Together with the seed this resumes a shuffled brute forcer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is greater than the number of strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// let brute_forcer = BruteForce::new_bounded(Charset::from("ABC"), 1..=3);
+    /// let mut shuffled = brute_forcer.shuffled(7);
+    /// shuffled.nth(9);
+    ///
+    /// let resumed = brute_forcer.shuffled(7).with_position(shuffled.position());
+    /// assert!(resumed.eq(shuffled));
+    /// ```
+    pub fn with_position(mut self, position: u128) -> Self {
+        assert!(
+            position <= self.permutation.size,
+            "The position must not be greater than the number of strings"
+        );
+        self.position = position;
+        self
+    }
+
+    /// Returns the number of strings left
+    pub fn remaining(&self) -> u128 {
+        self.permutation.size - self.position
+    }
+}
+
+impl<'a> BoundedBruteForce<'a> {
+    /// Returns a brute forcer which tries the remaining strings in a pseudo-random order
+    ///
+    /// The same seed always gives the same order.
+    pub fn shuffled(&self, seed: u64) -> ShuffledBruteForce<'a> {
+        let bounds = self.bounds();
+        ShuffledBruteForce {
+            brute_forcer: self.brute_forcer.clone(),
+            start: bounds.next,
+            permutation: Permutation::new(bounds.end - bounds.next, seed),
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for ShuffledBruteForce<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.position == self.permutation.size {
+            return None;
+        }
+        let index = self.start + self.permutation.apply(self.position);
+        self.position += 1;
+        Some(self.brute_forcer.candidate_at(index))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<String> {
+        self.position = self
+            .position
+            .saturating_add(n as u128)
+            .min(self.permutation.size);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// # Panics
+///
+/// `len` panics if the remaining keyspace does not fit in a `usize`.
+/// Use [`ShuffledBruteForce::remaining`] in this case.
+///
+/// [`ShuffledBruteForce::remaining`]: struct.ShuffledBruteForce.html#method.remaining
+impl ExactSizeIterator for ShuffledBruteForce<'_> {
+    fn len(&self) -> usize {
+        usize::try_from(self.remaining())
+            .expect("Only brute forcers with less than usize::MAX strings have a length")
+    }
+}
@@ -0,0 +1,232 @@
+//! Brute forcing strings into caller-provided buffers without any heap allocation
+//!
+//! [`SliceBruteForce`] borrows its chars and writes every string into a buffer
+//! of the caller, so it only uses `core`. It is available without the `std` and
+//! `alloc` features, e.g. on microcontrollers without an allocator.
+//!
+//! [`SliceBruteForce`]: struct.SliceBruteForce.html
+
+use core::ops::RangeInclusive;
+
+use crate::error::Error;
+use crate::{increment, offset_of_length, rank};
+
+/// Represents a brute-forcing instance which writes strings of at most `N` chars into buffers
+///
+/// The strings are tried in the same order as by [`BruteForce`].
+///
+/// [`BruteForce`]: ../struct.BruteForce.html
+///
+/// # Example
+///
+/// ```rust
+/// use bruteforce::slice::SliceBruteForce;
+///
+/// static CHARS: [char; 3] = ['A', 'B', 'C'];
+///
+/// let mut brute_forcer = SliceBruteForce::<4>::new_bounded(&CHARS, 1..=4);
+/// let mut buffer = [0u8; 4];
+/// while let Some(s) = brute_forcer.next_utf8(&mut buffer) {
+///     if s == "CAB" {
+///         break;
+///     }
+/// }
+/// assert_eq!(brute_forcer.next_utf8(&mut buffer), Some("CAC"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SliceBruteForce<'a, const N: usize> {
+    /// The chars to be tried at every position
+    chars: &'a [char],
+
+    /// The reversed representation of the next string, only the first `len` positions are used
+    raw: [usize; N],
+
+    /// The length of the next string
+    len: usize,
+
+    /// The maximum length
+    max: usize,
+
+    /// Whether every string was written
+    exhausted: bool,
+}
+
+impl<'a, const N: usize> SliceBruteForce<'a, N> {
+    /// Returns a brute forcer which tries every string of at most `N` chars, starting with the empty string
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - A slice that contains all chars to be tried
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` is empty.
+    pub fn new(chars: &'a [char]) -> SliceBruteForce<'a, N> {
+        Self::new_bounded(chars, 0..=N)
+    }
+
+    /// Returns a brute forcer which only tries strings within a length range
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - A slice that contains all chars to be tried
+    /// * `lengths` - The range of lengths of the strings
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` or the range is empty, or if the maximum length is greater than `N`.
+    pub fn new_bounded(
+        chars: &'a [char],
+        lengths: RangeInclusive<usize>,
+    ) -> SliceBruteForce<'a, N> {
+        Self::try_new_bounded(chars, lengths).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a brute forcer which only tries strings within a length range, or an error
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EmptyCharset`] if `chars` is empty, [`Error::EmptyLengthRange`]
+    /// if the range is empty and [`Error::CapacityExceeded`] if the maximum length is greater than `N`.
+    ///
+    /// [`Error::EmptyCharset`]: ../error/enum.Error.html#variant.EmptyCharset
+    /// [`Error::EmptyLengthRange`]: ../error/enum.Error.html#variant.EmptyLengthRange
+    /// [`Error::CapacityExceeded`]: ../error/enum.Error.html#variant.CapacityExceeded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::error::Error;
+    /// use bruteforce::slice::SliceBruteForce;
+    ///
+    /// let result = SliceBruteForce::<4>::try_new_bounded(&['A', 'B'], 1..=5);
+    /// assert_eq!(result.unwrap_err(), Error::CapacityExceeded);
+    /// ```
+    pub fn try_new_bounded(
+        chars: &'a [char],
+        lengths: RangeInclusive<usize>,
+    ) -> Result<SliceBruteForce<'a, N>, Error> {
+        let (min, max) = (*lengths.start(), *lengths.end());
+        if chars.is_empty() {
+            return Err(Error::EmptyCharset);
+        }
+        if min > max {
+            return Err(Error::EmptyLengthRange);
+        }
+        if max > N {
+            return Err(Error::CapacityExceeded);
+        }
+        Ok(SliceBruteForce {
+            chars,
+            raw: [0; N],
+            len: min,
+            max,
+            exhausted: false,
+        })
+    }
+
+    /// Returns the chars
+    pub fn chars(&self) -> &'a [char] {
+        self.chars
+    }
+
+    /// Returns the number of strings left, or `None` if it does not fit in a `u128`
+    pub fn remaining(&self) -> Option<u128> {
+        if self.exhausted {
+            return Some(0);
+        }
+        let end = offset_of_length(self.chars.len(), self.max + 1)?;
+        let next = rank(self.chars.len(), &self.raw[..self.len])?;
+        Some(end - next)
+    }
+
+    /// Writes the chars of the next string into a buffer
+    ///
+    /// Returns the written part of the buffer, or `None` if the keyspace is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::slice::SliceBruteForce;
+    ///
+    /// let mut brute_forcer = SliceBruteForce::<2>::new(&['A', 'B']);
+    /// let mut buffer = ['\0'; 2];
+    /// assert_eq!(brute_forcer.next_chars(&mut buffer), Some(&[][..]));
+    /// assert_eq!(brute_forcer.next_chars(&mut buffer), Some(&['A'][..]));
+    /// assert_eq!(brute_forcer.nth_chars(2, &mut buffer), Some(&['A', 'B'][..]));
+    /// ```
+    pub fn next_chars<'b>(&mut self, buffer: &'b mut [char]) -> Option<&'b [char]> {
+        if self.exhausted {
+            return None;
+        }
+        let len = self.len;
+        assert!(
+            buffer.len() >= len,
+            "The buffer must be large enough for the string"
+        );
+        for (c, &i) in buffer.iter_mut().zip(self.raw[..len].iter().rev()) {
+            *c = self.chars[i];
+        }
+        self.advance();
+        Some(&buffer[..len])
+    }
+
+    /// Skips `n` strings and writes the chars of the following one into a buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than the string.
+    pub fn nth_chars<'b>(&mut self, n: usize, buffer: &'b mut [char]) -> Option<&'b [char]> {
+        for _ in 0..n {
+            if self.exhausted {
+                return None;
+            }
+            self.advance();
+        }
+        self.next_chars(buffer)
+    }
+
+    /// Writes the UTF-8 encoding of the next string into a buffer
+    ///
+    /// Returns the written part of the buffer, or `None` if the keyspace is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than the encoded string.
+    pub fn next_utf8<'b>(&mut self, buffer: &'b mut [u8]) -> Option<&'b str> {
+        if self.exhausted {
+            return None;
+        }
+        let chars = self.chars;
+        let raw = &self.raw[..self.len];
+        let size = raw.iter().map(|&i| chars[i].len_utf8()).sum::<usize>();
+        assert!(
+            buffer.len() >= size,
+            "The buffer must be large enough for the string"
+        );
+        let mut offset = 0;
+        for &i in raw.iter().rev() {
+            offset += chars[i].encode_utf8(&mut buffer[offset..]).len();
+        }
+        self.advance();
+        // Every part of the buffer was written by `encode_utf8`
+        core::str::from_utf8(&buffer[..size]).ok()
+    }
+
+    /// Moves to the next string
+    fn advance(&mut self) {
+        let base = self.chars.len();
+        if increment(&mut self.raw[..self.len], |_| base).is_none() {
+            // Every position is 0 again, so the next string is the first one of the next length
+            if self.len == self.max {
+                self.exhausted = true;
+            } else {
+                self.len += 1;
+            }
+        }
+    }
+}
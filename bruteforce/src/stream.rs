@@ -0,0 +1,189 @@
+//! Brute forcing with asynchronous code
+//!
+//! [`BruteForce`] and [`BoundedBruteForce`] implement [`Stream`], and [`find_async`]
+//! and [`find_all_async`] verify the strings with an async predicate, like a database lookup,
+//! with bounded concurrency.
+//!
+//! [`BruteForce`]: ../struct.BruteForce.html
+//! [`BoundedBruteForce`]: ../struct.BoundedBruteForce.html
+//! [`Stream`]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
+//! [`find_async`]: ../struct.BruteForce.html#method.find_async
+//! [`find_all_async`]: ../struct.BoundedBruteForce.html#method.find_all_async
+//!
+//! # Example
+//!
+//! ```rust
+//! use bruteforce::BruteForce;
+//! use bruteforce::charset::Charset;
+//! use futures::executor::block_on;
+//! use futures::stream::StreamExt;
+//!
+//! // `BruteForce` is also an `Iterator`, so the methods of `StreamExt` must be called explicitly
+//! let brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=2);
+//! let strings = block_on(StreamExt::collect::<Vec<String>>(brute_forcer));
+//! assert_eq!(strings, ["A", "B", "AA", "AB", "BA", "BB"]);
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures::stream::{Stream, StreamExt};
+
+use crate::search::SearchResult;
+use crate::{BoundedBruteForce, BruteForce};
+
+/// The strings are generated synchronously, so the stream is always ready
+///
+/// A loop which only polls the stream never yields to the executor,
+/// so an unbounded brute forcer should be combined with other futures.
+impl Stream for BruteForce<'_> {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<String>> {
+        Poll::Ready(Iterator::next(&mut *self))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+impl Stream for BoundedBruteForce<'_> {
+    type Item = String;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<String>> {
+        Poll::Ready(Iterator::next(&mut *self))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+impl BruteForce<'_> {
+    /// Searches for the first string matching an async predicate
+    ///
+    /// Up to `concurrency` predicates run at the same time. The results are evaluated in order,
+    /// so the first match is the one with the smallest index. The predicates still running
+    /// after the match are dropped, and the brute forcer continues after the match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrency` is zero or if the global index of the next string does not fit
+    /// in a `u128`, because the brute forcer could not be moved back after the match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// use futures::executor::block_on;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("ABCPS"), 1..=4);
+    ///
+    /// let result = block_on(brute_forcer.find_async(|s| async move { s == "PASS" }, 8));
+    /// assert_eq!(result.first(), Some("PASS"));
+    /// assert_eq!(result.tried, brute_forcer.index_of("PASS").unwrap());
+    /// assert_eq!(brute_forcer.next(), Some("PBAA".to_string()));
+    /// ```
+    pub async fn find_async<P, F>(&mut self, predicate: P, concurrency: usize) -> SearchResult
+    where
+        P: FnMut(String) -> F,
+        F: Future<Output = bool>,
+    {
+        self.search_async(predicate, concurrency, true).await
+    }
+
+    async fn search_async<P, F>(
+        &mut self,
+        mut predicate: P,
+        concurrency: usize,
+        first_only: bool,
+    ) -> SearchResult
+    where
+        P: FnMut(String) -> F,
+        F: Future<Output = bool>,
+    {
+        assert!(concurrency > 0, "The concurrency must not be zero");
+        let start = Instant::now();
+        // The predicate takes the strings, so the matches are generated again from their index
+        let start_index = self
+            .index()
+            .expect("The index of the next string must fit in a u128");
+
+        let mut indices = Vec::new();
+        let mut tried = 0;
+        {
+            let mut verified = StreamExt::map(&mut *self, &mut predicate).buffered(concurrency);
+            while let Some(matched) = verified.next().await {
+                tried += 1;
+                if matched {
+                    indices.push(start_index + tried - 1);
+                    if first_only {
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Up to `concurrency` strings were taken after the match, so the brute forcer is moved back
+        if first_only {
+            self.seek(start_index + tried);
+        }
+        SearchResult {
+            matches: indices
+                .into_iter()
+                .map(|index| self.candidate_at(index))
+                .collect(),
+            tried,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+impl BoundedBruteForce<'_> {
+    /// Searches for the first string matching an async predicate
+    ///
+    /// See [`BruteForce::find_async`] for details.
+    ///
+    /// [`BruteForce::find_async`]: ../struct.BruteForce.html#method.find_async
+    pub async fn find_async<P, F>(&mut self, predicate: P, concurrency: usize) -> SearchResult
+    where
+        P: FnMut(String) -> F,
+        F: Future<Output = bool>,
+    {
+        self.brute_forcer.find_async(predicate, concurrency).await
+    }
+
+    /// Searches for all strings matching an async predicate
+    ///
+    /// Up to `concurrency` predicates run at the same time. The matches are sorted by their index.
+    /// The search ends when the keyspace is exhausted, so it is only available for bounded brute forcers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `concurrency` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bruteforce::BruteForce;
+    /// use bruteforce::charset::Charset;
+    /// use futures::executor::block_on;
+    /// let mut brute_forcer = BruteForce::new_bounded(Charset::from("AB"), 1..=3);
+    ///
+    /// let result = block_on(brute_forcer.find_all_async(|s| async move { s.starts_with("BA") }, 4));
+    /// assert_eq!(result.matches, ["BA", "BAA", "BAB"]);
+    /// assert_eq!(result.tried, 2 + 4 + 8);
+    /// ```
+    pub async fn find_all_async<P, F>(&mut self, predicate: P, concurrency: usize) -> SearchResult
+    where
+        P: FnMut(String) -> F,
+        F: Future<Output = bool>,
+    {
+        self.brute_forcer
+            .search_async(predicate, concurrency, false)
+            .await
+    }
+}